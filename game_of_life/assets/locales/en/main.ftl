@@ -0,0 +1,218 @@
+# User interface texts in English
+
+## Language
+
+# each language is named in its own language
+language-fr = Français
+language-en = English
+
+## Simulation
+
+new-grid = New Grid
+pause = Pause
+play = Play
+speed = { $value } s/step
+
+## "New Grid" generator
+
+generator-uniform = Random
+generator-soup = Centered soup
+generator-symmetric = Symmetric soup
+generator-noise = Clusters (Perlin)
+generator-regions = Regions
+generator-density = Density { $value }
+generator-soup-size = Soup
+generator-noise-scale = Clusters
+generator-regions-count = Regions
+# Catagolue symmetry class names, the same in every language
+symmetry-c1 = C1 (no symmetry)
+symmetry-c2-1 = C2_1
+symmetry-c2-2 = C2_2
+symmetry-c2-4 = C2_4
+symmetry-c4-1 = C4_1
+symmetry-c4-4 = C4_4
+symmetry-d2-plus-1 = D2_+1
+symmetry-d2-plus-2 = D2_+2
+symmetry-d2-x = D2_x
+symmetry-d4-plus-1 = D4_+1
+symmetry-d4-plus-2 = D4_+2
+symmetry-d4-plus-4 = D4_+4
+symmetry-d4-x1 = D4_x1
+symmetry-d4-x4 = D4_x4
+symmetry-d8-1 = D8_1
+symmetry-d8-4 = D8_4
+
+## Panel sections
+
+section-generator = Generation
+section-view = Display
+section-edit = Editing
+section-mutations = Mutations
+section-stats = Statistics
+section-export = Export
+section-settings = Preferences
+
+## View and coloring
+
+view-fit = Fit
+view-reset = Reset view
+color-strain = Strains
+color-age = Age
+color-trail = Trail
+
+## Activity heatmap
+
+heatmap-changes = Activity
+heatmap-presence = Presence
+heatmap-strains = Contest
+heatmap-opaque = Opaque
+heatmap-clear = Clear
+heatmap-export = Export
+heatmap-saved = Activity map saved: { $png } and { $csv }
+heatmap-failed = Activity map not saved: { $error }
+
+## Selection
+
+selection-copy = Copy
+selection-cut = Cut
+selection-paste = Paste
+selection-rotate = Rotate
+selection-flip-h = Flip H
+selection-flip-v = Flip V
+selection-fill = Fill
+selection-clear = Clear
+selection-randomize = Random
+draw-mode = Draw mode
+
+## Library
+
+library = Library
+library-title = Pattern library
+library-hint = Click a pattern, then the grid to place it (Esc to finish)
+library-filter = Search…
+library-no-match = No pattern found
+library-user-dir =
+    Your patterns (.rle, .cells):
+    { $dir }
+category-canons = Guns
+category-mathusalems = Methuselahs
+category-oscillateurs = Oscillators
+category-puffers = Puffers
+category-vaisseaux = Spaceships
+category-divers = Misc
+category-mes-motifs = My patterns
+
+## Mutations and stabilization
+
+mutation-blue = Blue Mutation
+mutation-red = Red Mutation
+state-running = State: evolving
+state-empty = State: empty (gen. { $generation })
+state-static = State: still (gen. { $generation })
+state-periodic = State: period { $period } (gen. { $generation })
+auto-pause = Auto pause
+auto-reset = Auto new grid
+
+## Graph
+
+graph-generation = Gen. { $generation }
+graph-total = Total { $total }
+graph-lines = View: lines
+graph-stacked = View: stacked areas
+
+## Recording, export and session
+
+record-start = Record CSV
+record-stop = Stop recording
+export-image = Image
+export-animate = Animate
+export-format = Format
+export-frames = Frames
+export-image-saved = Image saved: { $path }
+export-image-failed = Image not saved: { $error }
+export-animation-saved = Animation saved ({ $frames ->
+        [one] { $frames } frame
+       *[other] { $frames } frames
+    }): { $path }
+export-animation-failed = Animation not saved: { $error }
+save-session = Save session
+session-saved = Session saved: { $path }
+session-failed = Could not save { $path }: { $error }
+session-none = No session (.gol) in the current folder
+file-placed = { $file } placed
+file-loaded = { $file } loaded
+file-failed = { $file }: { $error }
+file-too-large = { $file }: grid too large ({ $width }×{ $height }, at most { $max }×{ $max })
+
+## Preferences
+
+settings-reset-button = Reset defaults
+settings-reset = Default settings restored (theme on next launch)
+
+## Scripts
+
+script-failed = Script stopped: { $error }
+script-callback-failed = on_generation callback removed: { $error }
+
+## Console
+
+console-rule = Rule: { $rule }
+console-seed = Seed: { $seed }
+console-set = { $setting } = { $value }
+console-stats = Generation { $generation }: { $total } cells ({ $normal } normal, { $blue } blue, { $red } red)
+console-unknown = Unknown command: { $command } (type help for the list)
+console-help-step = step [n] — advance n generations (1 by default)
+console-help-rule = rule [B36/S23] — show or change the rule
+console-help-load = load <file> — open a pattern or a session
+console-help-seed = seed <n> — reseed the random generator
+console-help-set = set <blue|red on/off, blue.prob|red.prob 0–1, speed seconds> — change a setting
+console-help-stats = stats — population of the current generation
+console-help-run = run <script.rhai> — run a script
+console-help-eval = eval <code> — run one line of Rhai
+console-help-clear = clear — clear the log
+console-help-help = help — list the commands
+
+## Keyboard shortcuts
+
+help-title = Keyboard shortcuts (? to close)
+help-hint = Keys can be changed in { $file }
+action-play-pause = Play / pause
+action-step = Step one generation
+action-speed-up = Speed up
+action-speed-down = Slow down
+action-randomize = New random grid
+action-reset = Back to generation 0
+action-clear = Clear the grid
+action-undo = Undo
+action-redo = Redo
+action-zoom-in = Zoom in
+action-zoom-out = Zoom out
+action-fit-view = Fit view
+action-reset-view = Initial view
+action-pan-left = Pan left
+action-pan-right = Pan right
+action-pan-up = Pan up
+action-pan-down = Pan down
+action-grid-lines = Grid lines
+action-save = Save session
+action-load = Reload latest session
+action-export-image = Export image
+action-copy = Copy selection
+action-cut = Cut selection
+action-paste = Paste
+action-rotate = Rotate
+action-flip-horizontal = Flip horizontally
+action-flip-vertical = Flip vertically
+action-fill = Fill selection
+action-clear-selection = Clear selection
+action-randomize-selection = Randomize selection
+action-draw-mode = Draw mode (symmetric)
+action-console = Command console
+action-cancel = Cancel selection or paste
+action-help = Show help
+
+## Census
+
+census = Census
+census-generation = Gen. { $generation }
+census-empty = (no objects)
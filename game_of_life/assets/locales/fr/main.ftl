@@ -0,0 +1,218 @@
+# Textes de l’interface en français (langue de référence)
+
+## Langue
+
+# chaque langue est nommée dans sa propre langue
+language-fr = Français
+language-en = English
+
+## Simulation
+
+new-grid = Nouvelle Grille
+pause = Pause
+play = Lecture
+speed = { $value } s/étape
+
+## Générateur de « Nouvelle Grille »
+
+generator-uniform = Aléatoire
+generator-soup = Soupe centrée
+generator-symmetric = Soupe symétrique
+generator-noise = Amas (Perlin)
+generator-regions = Régions
+generator-density = Densité { $value }
+generator-soup-size = Soupe
+generator-noise-scale = Amas
+generator-regions-count = Régions
+# noms des classes de symétrie de Catagolue, identiques dans toutes les langues
+symmetry-c1 = C1 (sans symétrie)
+symmetry-c2-1 = C2_1
+symmetry-c2-2 = C2_2
+symmetry-c2-4 = C2_4
+symmetry-c4-1 = C4_1
+symmetry-c4-4 = C4_4
+symmetry-d2-plus-1 = D2_+1
+symmetry-d2-plus-2 = D2_+2
+symmetry-d2-x = D2_x
+symmetry-d4-plus-1 = D4_+1
+symmetry-d4-plus-2 = D4_+2
+symmetry-d4-plus-4 = D4_+4
+symmetry-d4-x1 = D4_x1
+symmetry-d4-x4 = D4_x4
+symmetry-d8-1 = D8_1
+symmetry-d8-4 = D8_4
+
+## Sections du panneau
+
+section-generator = Génération
+section-view = Affichage
+section-edit = Édition
+section-mutations = Mutations
+section-stats = Statistiques
+section-export = Export
+section-settings = Préférences
+
+## Vue et coloration
+
+view-fit = Ajuster
+view-reset = Vue initiale
+color-strain = Souches
+color-age = Âge
+color-trail = Traînée
+
+## Carte d’activité
+
+heatmap-changes = Activité
+heatmap-presence = Présence
+heatmap-strains = Conflits
+heatmap-opaque = Opaque
+heatmap-clear = Effacer
+heatmap-export = Exporter
+heatmap-saved = Carte d’activité enregistrée : { $png } et { $csv }
+heatmap-failed = Carte d’activité non enregistrée : { $error }
+
+## Sélection
+
+selection-copy = Copier
+selection-cut = Couper
+selection-paste = Coller
+selection-rotate = Pivoter
+selection-flip-h = Miroir H
+selection-flip-v = Miroir V
+selection-fill = Remplir
+selection-clear = Vider
+selection-randomize = Aléatoire
+draw-mode = Mode dessin
+
+## Bibliothèque
+
+library = Bibliothèque
+library-title = Bibliothèque de motifs
+library-hint = Cliquer un motif puis la grille pour le poser (Échap pour finir)
+library-filter = Rechercher…
+library-no-match = Aucun motif trouvé
+library-user-dir =
+    Motifs personnels (.rle, .cells) :
+    { $dir }
+category-canons = Canons
+category-mathusalems = Mathusalems
+category-oscillateurs = Oscillateurs
+category-puffers = Puffers
+category-vaisseaux = Vaisseaux
+category-divers = Divers
+category-mes-motifs = Mes motifs
+
+## Mutations et stabilisation
+
+mutation-blue = Mutation Bleue
+mutation-red = Mutation Rouge
+state-running = État : en évolution
+state-empty = État : vide (gén. { $generation })
+state-static = État : stable (gén. { $generation })
+state-periodic = État : période { $period } (gén. { $generation })
+auto-pause = Pause auto
+auto-reset = Nouvelle grille auto
+
+## Graphe
+
+graph-generation = Gén. { $generation }
+graph-total = Total { $total }
+graph-lines = Vue : courbes
+graph-stacked = Vue : aires empilées
+
+## Enregistrement, export et session
+
+record-start = Enregistrer CSV
+record-stop = Arrêter l’enregistrement
+export-image = Image
+export-animate = Animer
+export-format = Format
+export-frames = Images
+export-image-saved = Image enregistrée : { $path }
+export-image-failed = Image non enregistrée : { $error }
+export-animation-saved = Animation enregistrée ({ $frames ->
+        [one] { $frames } image
+       *[other] { $frames } images
+    }) : { $path }
+export-animation-failed = Animation non enregistrée : { $error }
+save-session = Sauver la session
+session-saved = Session enregistrée : { $path }
+session-failed = Impossible d’enregistrer { $path } : { $error }
+session-none = Aucune session (.gol) dans le dossier courant
+file-placed = { $file } posé
+file-loaded = { $file } chargé
+file-failed = { $file } : { $error }
+file-too-large = { $file } : grille trop grande ({ $width }×{ $height }, au plus { $max }×{ $max })
+
+## Préférences
+
+settings-reset-button = Réinitialiser
+settings-reset = Réglages par défaut rétablis (thème au prochain lancement)
+
+## Scripts
+
+script-failed = Script interrompu : { $error }
+script-callback-failed = Rappel on_generation retiré : { $error }
+
+## Console
+
+console-rule = Règle : { $rule }
+console-seed = Graine : { $seed }
+console-set = { $setting } = { $value }
+console-stats = Génération { $generation } : { $total } cellules ({ $normal } normales, { $blue } bleues, { $red } rouges)
+console-unknown = Commande inconnue : { $command } (help pour la liste)
+console-help-step = step [n] — avance de n générations (1 par défaut)
+console-help-rule = rule [B36/S23] — affiche ou change la règle
+console-help-load = load <fichier> — ouvre un motif ou une session
+console-help-seed = seed <n> — réinitialise le générateur aléatoire
+console-help-set = set <blue|red on/off, blue.prob|red.prob 0–1, speed secondes> — change un réglage
+console-help-stats = stats — population de la génération courante
+console-help-run = run <script.rhai> — exécute un script
+console-help-eval = eval <code> — exécute une ligne de Rhai
+console-help-clear = clear — vide le journal
+console-help-help = help — liste des commandes
+
+## Raccourcis clavier
+
+help-title = Raccourcis clavier (? pour fermer)
+help-hint = Touches modifiables dans { $file }
+action-play-pause = Lecture / pause
+action-step = Avancer d’une génération
+action-speed-up = Accélérer
+action-speed-down = Ralentir
+action-randomize = Nouvelle grille aléatoire
+action-reset = Revenir à la génération 0
+action-clear = Vider la grille
+action-undo = Annuler
+action-redo = Rétablir
+action-zoom-in = Zoomer
+action-zoom-out = Dézoomer
+action-fit-view = Ajuster la vue
+action-reset-view = Vue initiale
+action-pan-left = Vue vers la gauche
+action-pan-right = Vue vers la droite
+action-pan-up = Vue vers le haut
+action-pan-down = Vue vers le bas
+action-grid-lines = Lignes de la grille
+action-save = Sauver la session
+action-load = Recharger la dernière session
+action-export-image = Exporter une image
+action-copy = Copier la sélection
+action-cut = Couper la sélection
+action-paste = Coller
+action-rotate = Pivoter
+action-flip-horizontal = Miroir horizontal
+action-flip-vertical = Miroir vertical
+action-fill = Remplir la sélection
+action-clear-selection = Vider la sélection
+action-randomize-selection = Sélection aléatoire
+action-draw-mode = Mode dessin (symétrique)
+action-console = Console de commandes
+action-cancel = Annuler la sélection ou le collage
+action-help = Afficher l’aide
+
+## Recensement
+
+census = Recensement
+census-generation = Gén. { $generation }
+census-empty = (aucun objet)
@@ -0,0 +1,117 @@
+use clap::ValueEnum;
+use rand::{rngs::StdRng, SeedableRng};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::{
+    detection::{CycleDetector, StabilizationKind},
+    generator::Generator,
+    grid::GridConfig,
+    mutation_setting::MutationSettings
+};
+
+/// Paramètres d’une soupe aléatoire exécutée sans fenêtre
+#[derive(Clone)]
+pub struct SoupConfig {
+    pub grid: GridConfig,
+    pub max_generations: u64,
+    pub settings: MutationSettings,
+    pub generator: Generator,
+}
+
+/// Résumé d’une exécution
+#[derive(Clone, Debug, Serialize)]
+pub struct SoupResult {
+    pub seed: u64,
+    pub generations: u64,
+    pub total: usize,
+    pub normal: usize,
+    pub blue: usize,
+    pub red: usize,
+    /// `empty`, `static`, `periodic`, ou `running` si la limite de générations est atteinte
+    pub outcome: &'static str,
+    pub stabilized_at: Option<u64>,
+    pub period: Option<usize>,
+}
+
+/// Fait évoluer une soupe jusqu’à sa stabilisation ou la limite de générations
+pub fn run_soup(config: &SoupConfig, seed: u64) -> SoupResult {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut grid = config.generator.generate(&config.grid, &config.settings, &mut rng);
+    let mut detector = CycleDetector::default();
+
+    let mut stats = grid.stats();
+    let mut stabilization = detector.observe(&stats);
+    while stabilization.is_none() && grid.generation < config.max_generations {
+        stats = grid.step(&config.settings, &mut rng);
+        stabilization = detector.observe(&stats);
+    }
+
+    SoupResult {
+        seed,
+        generations: grid.generation,
+        total: stats.total,
+        normal: stats.normal,
+        blue: stats.blue,
+        red: stats.red,
+        outcome: match stabilization.map(|s| s.kind) {
+            None => "running",
+            Some(StabilizationKind::Empty) => "empty",
+            Some(StabilizationKind::Static) => "static",
+            Some(StabilizationKind::Periodic) => "periodic",
+        },
+        stabilized_at: stabilization.map(|s| s.generation),
+        period: stabilization.map(|s| s.period),
+    }
+}
+
+pub fn write_csv(results: &[SoupResult], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "seed,generations,total,normal,blue,red,outcome,stabilized_at,period")?;
+    for r in results {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            r.seed,
+            r.generations,
+            r.total,
+            r.normal,
+            r.blue,
+            r.red,
+            r.outcome,
+            r.stabilized_at.map_or(String::new(), |g| g.to_string()),
+            r.period.map_or(String::new(), |p| p.to_string()),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn write_json(results: &[SoupResult], out: &mut impl Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, results).map_err(io::Error::other)?;
+    writeln!(out)
+}
+
+/// Format des fichiers de résultats
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Csv,
+    Json,
+}
+
+impl OutputFormat {
+    /// Format explicite, sinon déduit de l’extension (CSV par défaut)
+    pub fn resolve(format: Option<OutputFormat>, path: Option<&Path>) -> OutputFormat {
+        format.unwrap_or(match path {
+            Some(path) if path.extension().is_some_and(|e| e == "json") => OutputFormat::Json,
+            _ => OutputFormat::Csv,
+        })
+    }
+}
+
+/// Fichier de sortie, ou sortie standard si aucun chemin n’est donné
+pub fn open_output(path: Option<&Path>) -> io::Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    })
+}
@@ -0,0 +1,68 @@
+//! Exécution sans fenêtre de soupes aléatoires, avec les mêmes règles que l’application
+//!
+//! `cargo run --bin batch -- --runs 100 --generations 2000 --output resultats.csv`
+
+use clap::Parser;
+use game_of_life::{
+    batch::{open_output, run_soup, write_csv, write_json, OutputFormat, SoupConfig},
+    cli::SimulationArgs
+};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Exécute des soupes aléatoires du jeu de la vie sans affichage")]
+struct Args {
+    /// Nombre de soupes
+    #[arg(long, default_value_t = 100)]
+    runs: u64,
+    /// Première graine (les suivantes sont consécutives)
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Nombre maximal de générations par soupe
+    #[arg(long, default_value_t = 1000)]
+    generations: u64,
+    #[command(flatten)]
+    simulation: SimulationArgs,
+    /// Format de sortie (déduit de l’extension de --output si absent)
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+    /// Fichier de sortie (sortie standard par défaut)
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let config = SoupConfig {
+        grid: args.simulation.grid_config(None),
+        max_generations: args.generations,
+        settings: args.simulation.mutation_settings(),
+        generator: args.simulation.generator.clone(),
+    };
+    let results: Vec<_> = (args.seed..args.seed + args.runs)
+        .map(|seed| run_soup(&config, seed))
+        .collect();
+
+    let output = args.output.as_deref();
+    let mut out = match open_output(output) {
+        Ok(out) => out,
+        Err(e) => {
+            eprintln!("Impossible de créer le fichier de sortie : {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let written = match OutputFormat::resolve(args.format, output) {
+        OutputFormat::Csv => write_csv(&results, &mut out),
+        OutputFormat::Json => write_json(&results, &mut out),
+    }
+    .and_then(|_| out.flush());
+    if let Err(e) = written {
+        eprintln!("Erreur d’écriture : {e}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
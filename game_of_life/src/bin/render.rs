@@ -0,0 +1,96 @@
+//! Rendu sans fenêtre d’une partie en image PNG ou en animation GIF / APNG
+//!
+//! `cargo run --bin render -- --pattern assets/patterns/canons/gosper_glider_gun.rle --generations 120 --output canon.gif`
+
+use clap::Parser;
+use game_of_life::{
+    cli::SimulationArgs,
+    export::{save, Frame, ImageFormat},
+    grid::Grid,
+    pattern::Pattern,
+    theme::ThemeArgs
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Enregistre une partie du jeu de la vie en image ou en animation, sans affichage")]
+struct Args {
+    #[command(flatten)]
+    simulation: SimulationArgs,
+    #[command(flatten)]
+    appearance: ThemeArgs,
+    /// Graine aléatoire (soupe initiale et mutations)
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Motif initial (.rle, .cells, .mc) au centre d’une grille vide, à la place d’une soupe
+    #[arg(long)]
+    pattern: Option<PathBuf>,
+    /// Générations simulées : un PNG montre la dernière, une animation les contient toutes
+    #[arg(long, default_value_t = 100)]
+    generations: u64,
+    /// Pixels par cellule
+    #[arg(long, default_value_t = 10)]
+    scale: u32,
+    /// Durée d’une image de l’animation, en millisecondes
+    #[arg(long, default_value_t = 100)]
+    delay: u32,
+    /// Format de sortie (déduit de l’extension de --output si absent)
+    #[arg(long, value_enum)]
+    format: Option<ImageFormat>,
+    /// Fichier de sortie
+    #[arg(long)]
+    output: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let pattern = match &args.pattern {
+        Some(path) => match Pattern::load(path) {
+            Ok(pattern) => Some(pattern),
+            Err(e) => {
+                eprintln!("Impossible de charger {} : {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let theme = match args.appearance.theme() {
+        Ok(theme) => theme,
+        Err(e) => {
+            eprintln!("Thème illisible : {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let config = args.simulation.grid_config(pattern.as_ref().and_then(|p| p.rule));
+    let settings = args.simulation.mutation_settings();
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let mut grid = match &pattern {
+        Some(pattern) => Grid::with_pattern(&config, pattern),
+        None => args.simulation.generator.generate(&config, &settings, &mut rng),
+    };
+
+    // un PNG n’a besoin que de la dernière génération
+    let format = ImageFormat::resolve(args.format, &args.output);
+    let animated = format != ImageFormat::Png;
+    let mut frames = vec![Frame::from_grid(&grid, &theme)];
+    for _ in 0..args.generations {
+        grid.step(&settings, &mut rng);
+        if animated {
+            frames.push(Frame::from_grid(&grid, &theme));
+        }
+    }
+    if !animated {
+        frames = vec![Frame::from_grid(&grid, &theme)];
+    }
+
+    if let Err(e) = save(&args.output, format, &frames, args.scale.max(1), args.delay) {
+        eprintln!("{} : {e}", args.output.display());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
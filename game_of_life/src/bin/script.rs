@@ -0,0 +1,62 @@
+//! Exécution sans fenêtre d’un script Rhai, avec les mêmes règles que l’application
+//!
+//! `cargo run --bin script -- experience.rhai --width 64 --height 64 --seed 1`
+
+use clap::Parser;
+use game_of_life::{
+    cli::SimulationArgs,
+    grid::Grid,
+    pattern::Pattern,
+    scripting::{ScriptHost, ScriptWorld}
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Exécute un script Rhai du jeu de la vie sans affichage")]
+struct Args {
+    /// Script à exécuter
+    script: PathBuf,
+    /// Graine aléatoire
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Motif initial (.rle, .cells) placé au centre d’une grille vide, au lieu d’une grille aléatoire
+    #[arg(long)]
+    pattern: Option<PathBuf>,
+    #[command(flatten)]
+    simulation: SimulationArgs,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let pattern = match &args.pattern {
+        Some(path) => match Pattern::load(path) {
+            Ok(pattern) => Some(pattern),
+            Err(e) => {
+                eprintln!("Impossible de charger {} : {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let config = args.simulation.grid_config(pattern.as_ref().and_then(|p| p.rule));
+    let settings = args.simulation.mutation_settings();
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let grid = match &pattern {
+        Some(pattern) => Grid::with_pattern(&config, pattern),
+        None => args.simulation.generator.generate(&config, &settings, &mut rng),
+    };
+
+    let mut host = ScriptHost::new(ScriptWorld::new(grid, settings, rng));
+    host.on_print(|line| println!("{line}"));
+    // rien à figer sans fenêtre : Ctrl+C suffit
+    host.set_time_limit(None);
+    if let Err(e) = host.run_file(&args.script) {
+        eprintln!("{} : {e}", args.script.display());
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
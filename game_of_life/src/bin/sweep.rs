@@ -0,0 +1,95 @@
+//! Balayage de paramètres de mutation : chaque combinaison est exécutée sur
+//! plusieurs graines en parallèle, puis résumée (moyenne, variance)
+//!
+//! `cargo run --release --bin sweep -- --blue 0:0.3:0.05 --red 0:0.3:0.05 --rules B3/S23,B36/S23 --seeds 100`
+
+use clap::Parser;
+use game_of_life::{
+    batch::{open_output, OutputFormat, SoupConfig},
+    cli::SimulationArgs,
+    rule::Rule,
+    sweep::{run_sweep, write_csv, write_json, SweepSpec, ValueRange}
+};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Balaye les probabilités de mutation et les règles sur de nombreuses soupes")]
+struct Args {
+    /// Probabilité bleue : valeur ou plage début:fin:pas (--blue-prob par défaut)
+    #[arg(long)]
+    blue: Option<ValueRange>,
+    /// Probabilité rouge : valeur ou plage début:fin:pas (--red-prob par défaut)
+    #[arg(long)]
+    red: Option<ValueRange>,
+    /// Règles de base à comparer, séparées par des virgules (--rule par défaut)
+    #[arg(long, value_delimiter = ',')]
+    rules: Vec<Rule>,
+    /// Nombre de graines par combinaison
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+    /// Première graine
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Nombre maximal de générations par soupe
+    #[arg(long, default_value_t = 1000)]
+    generations: u64,
+    /// Grille, bords, mutations activées et générateur communs à toutes les combinaisons
+    #[command(flatten)]
+    simulation: SimulationArgs,
+    /// Format de sortie (déduit de l’extension de --output si absent)
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+    /// Fichier de sortie (sortie standard par défaut)
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let settings = args.simulation.mutation_settings();
+    let grid = args.simulation.grid_config(None);
+    // sans plage, la valeur de l’option de simulation correspondante
+    let single = |p: f32| ValueRange { start: p, end: p, step: 0.0 };
+    let blue = args.blue.unwrap_or(single(settings.blue_probability));
+    let red = args.red.unwrap_or(single(settings.red_probability));
+    let rules = if args.rules.is_empty() { vec![grid.rule] } else { args.rules };
+
+    let spec = SweepSpec {
+        base: SoupConfig {
+            grid,
+            max_generations: args.generations,
+            settings,
+            generator: args.simulation.generator.clone(),
+        },
+        // bornées comme --blue-prob et --red-prob
+        blue_probabilities: blue.values().into_iter().map(|p| p.clamp(0.0, 1.0)).collect(),
+        red_probabilities: red.values().into_iter().map(|p| p.clamp(0.0, 1.0)).collect(),
+        rules,
+        first_seed: args.seed,
+        seeds: args.seeds,
+    };
+    let results = run_sweep(&spec);
+
+    let output = args.output.as_deref();
+    let mut out = match open_output(output) {
+        Ok(out) => out,
+        Err(e) => {
+            eprintln!("Impossible de créer le fichier de sortie : {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let written = match OutputFormat::resolve(args.format, output) {
+        OutputFormat::Csv => write_csv(&results, &mut out),
+        OutputFormat::Json => write_json(&results, &mut out),
+    }
+    .and_then(|_| out.flush());
+    if let Err(e) = written {
+        eprintln!("Erreur d’écriture : {e}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
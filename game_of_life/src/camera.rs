@@ -0,0 +1,190 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::PrimaryWindow;
+use crate::{
+    grid::GridConfig,
+    stats::PopulationHistory,
+    shortcuts::{Action, HeldActions},
+    systems::{cell_to_world, CELL_SIZE}
+};
+
+/// Part de la fenêtre occupée par le panneau de gauche
+pub const PANEL_FRACTION: f32 = 0.25;
+
+const MIN_SCALE: f32 = 0.02;
+const MAX_SCALE: f32 = 20.0;
+const ZOOM_STEP: f32 = 1.1;
+const PAN_SPEED: f32 = 600.0; // pixels écran par seconde
+
+/// Caméra qui affiche la grille (la seule qui zoome et se déplace)
+#[derive(Component)]
+pub struct GridCamera;
+
+/// Actions sur la vue de la grille
+#[derive(Event, Clone, Copy)]
+pub enum ViewEvent {
+    /// Cadre les cellules vivantes
+    FitPattern,
+    /// Recentre et affiche toute la grille
+    Reset,
+    /// Zoom autour du centre de la vue, en crans de molette (positif : plus près)
+    Zoom(f32),
+}
+
+#[derive(Component, Clone, Copy)]
+pub enum ViewButton {
+    FitPattern,
+    Reset,
+}
+
+/// Zone de la fenêtre (en pixels logiques) réservée à la grille : à droite du panneau
+pub fn grid_viewport_rect(window: &Window) -> Rect {
+    let left = window.width() * PANEL_FRACTION;
+    Rect::new(left, 0.0, window.width(), window.height())
+}
+
+/// Le pointeur survole un élément interactif de l’interface (bouton, liste…) : la grille l’ignore
+pub fn pointer_over_ui(interactions: &Query<&Interaction>) -> bool {
+    interactions.iter().any(|i| *i != Interaction::None)
+}
+
+/// Position monde sous un point de l’écran (coordonnées fenêtre, y vers le bas)
+pub fn screen_to_world(window: &Window, camera: &Transform, scale: f32, screen: Vec2) -> Vec2 {
+    let offset = screen - grid_viewport_rect(window).center();
+    camera.translation.truncate() + Vec2::new(offset.x, -offset.y) * scale
+}
+
+// La caméra de la grille ne dessine qu’à droite du panneau : aucune cellule n’est cachée
+pub fn update_grid_viewport(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Camera, With<GridCamera>>,
+) {
+    let (Ok(window), Ok(mut camera)) = (windows.get_single(), cameras.get_single_mut()) else {
+        return;
+    };
+    let left = (window.physical_width() as f32 * PANEL_FRACTION) as u32;
+    let size = UVec2::new(window.physical_width().saturating_sub(left), window.physical_height());
+    if size.x == 0 || size.y == 0 {
+        return;
+    }
+
+    let viewport = Viewport {
+        physical_position: UVec2::new(left, 0),
+        physical_size: size,
+        ..default()
+    };
+    let unchanged = camera.viewport.as_ref().is_some_and(|v| {
+        v.physical_position == viewport.physical_position && v.physical_size == viewport.physical_size
+    });
+    if !unchanged {
+        camera.viewport = Some(viewport);
+    }
+}
+
+// 🖱️ Molette : zoom centré sur le curseur
+pub fn zoom_camera(
+    mut wheel: EventReader<MouseWheel>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<GridCamera>>,
+    interactions: Query<&Interaction>,
+) {
+    let scroll: f32 = wheel
+        .read()
+        .map(|e| match e.unit {
+            MouseScrollUnit::Line => e.y,
+            MouseScrollUnit::Pixel => e.y / 100.0,
+        })
+        .sum();
+    if scroll == 0.0 || pointer_over_ui(&interactions) {
+        return;
+    }
+    let (Ok(window), Ok((mut transform, mut projection))) = (windows.get_single(), cameras.get_single_mut()) else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position().filter(|c| grid_viewport_rect(window).contains(*c)) else {
+        return;
+    };
+
+    // le point sous le curseur reste fixe pendant le zoom
+    let before = screen_to_world(window, &transform, projection.scale, cursor);
+    projection.scale = (projection.scale * ZOOM_STEP.powf(-scroll)).clamp(MIN_SCALE, MAX_SCALE);
+    let after = screen_to_world(window, &transform, projection.scale, cursor);
+    transform.translation += (before - after).extend(0.0);
+}
+
+// Clic milieu glissé ou touches de déplacement (flèches par défaut) : déplacement de la vue
+pub fn pan_camera(
+    time: Res<Time>,
+    held: Res<HeldActions>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Transform, &OrthographicProjection), With<GridCamera>>,
+    mut last_drag: Local<Option<Vec2>>,
+) {
+    let (Ok(window), Ok((mut transform, projection))) = (windows.get_single(), cameras.get_single_mut()) else {
+        return;
+    };
+
+    let cursor = window.cursor_position();
+    if buttons.pressed(MouseButton::Middle) {
+        if let (Some(last), Some(current)) = (*last_drag, cursor) {
+            let delta = current - last;
+            transform.translation -= Vec3::new(delta.x, -delta.y, 0.0) * projection.scale;
+        }
+        *last_drag = cursor;
+    } else {
+        *last_drag = None;
+    }
+
+    let mut direction = Vec2::ZERO;
+    if held.pressed(Action::PanLeft) { direction.x -= 1.0; }
+    if held.pressed(Action::PanRight) { direction.x += 1.0; }
+    if held.pressed(Action::PanDown) { direction.y -= 1.0; }
+    if held.pressed(Action::PanUp) { direction.y += 1.0; }
+    if direction != Vec2::ZERO {
+        let step = direction.normalize() * PAN_SPEED * projection.scale * time.delta_seconds();
+        transform.translation += step.extend(0.0);
+    }
+}
+
+// Cadrage : sur les cellules vivantes ou sur toute la grille
+pub fn view_event_system(
+    mut events: EventReader<ViewEvent>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<GridConfig>,
+    history: Res<PopulationHistory>,
+    mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<GridCamera>>,
+) {
+    let (Ok(window), Ok((mut transform, mut projection))) = (windows.get_single(), cameras.get_single_mut()) else {
+        return;
+    };
+    let view = grid_viewport_rect(window).size();
+
+    for event in events.read() {
+        let bounds = match event {
+            ViewEvent::FitPattern => history.latest().and_then(|s| s.bounds),
+            ViewEvent::Reset => None,
+            ViewEvent::Zoom(steps) => {
+                // la caméra vise le centre de la vue : il reste fixe
+                projection.scale = (projection.scale * ZOOM_STEP.powf(-steps)).clamp(MIN_SCALE, MAX_SCALE);
+                continue;
+            }
+        }
+        .unwrap_or((0, 0, config.width - 1, config.height - 1));
+
+        let (min_x, min_y, max_x, max_y) = bounds;
+        let a = cell_to_world(config.width, config.height, min_x, min_y);
+        let b = cell_to_world(config.width, config.height, max_x, max_y);
+        let size = (a - b).abs() + Vec2::splat(CELL_SIZE);
+
+        // marge de 10 % ; la vue initiale ne grossit pas au-delà de la taille normale
+        let fit = (size.x / view.x).max(size.y / view.y) * 1.1;
+        projection.scale = match event {
+            ViewEvent::Reset => fit.max(1.0),
+            _ => fit,
+        }
+        .clamp(MIN_SCALE, MAX_SCALE);
+        transform.translation = ((a + b) / 2.0).extend(transform.translation.z);
+    }
+}
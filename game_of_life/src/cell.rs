@@ -1,46 +1,99 @@
 use bevy::prelude::*; // nécessaire ici car ce fichier a son propre scope
-use rand::random;
-use crate::mutation_setting::MutationSettings;
+use clap::ValueEnum;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::{mutation_setting::MutationSettings, rule::Rule, theme::Theme};
+
+/// Générations d’âge au-delà desquelles la couleur « âge » ne change plus
+const AGE_SCALE: f32 = 64.0;
+/// Durée de la traînée laissée par une cellule morte, en générations
+const TRAIL_LENGTH: u32 = 12;
 
 // Composant représentant une cellule
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
 pub struct Cell {
     pub alive: bool, // état vivant ou mort
     pub mutation: MutationType,
     pub x: usize, // position dans la grille
     pub y: usize,
+    /// Générations passées en vie sans interruption (0 à la naissance)
+    pub age: u32,
+    /// Générations depuis la mort (`Cell::NEVER_ALIVE` si elle n’a jamais vécu)
+    pub dead_for: u32,
+}
+
+/// Coloration des cellules à l’écran
+#[derive(Resource, Component, Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum ColorMode {
+    /// Couleur de la souche (mutation)
+    #[default]
+    Strain,
+    /// Des jeunes (clair) aux anciennes (sombre)
+    Age,
+    /// Souche, plus une traînée qui s’estompe derrière les cellules mortes
+    Trail,
 }
 
-/// Type de mutation possible
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Type de mutation possible (« normal », « blue », « red » en JSON)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MutationType {
+    #[default]
+    #[serde(rename = "normal")]
     None,
     Blue,
     Red,
 }
 
+impl MutationType {
+    /// Toutes les souches, dans l’ordre d’affichage
+    pub const ALL: [MutationType; 3] = [MutationType::None, MutationType::Blue, MutationType::Red];
+}
+
 impl Cell {
-    pub fn new(x: usize, y: usize,settings: &MutationSettings) -> Self {
+    pub const NEVER_ALIVE: u32 = u32::MAX;
+
+    /// Cellule vivante avec la probabilité `density`
+    pub fn new(x: usize, y: usize, density: f64, settings: &MutationSettings, rng: &mut impl Rng) -> Self {
         let mut cell = Self {
-            alive: rand::random::<bool>(),
+            alive: rng.gen_bool(density),
             mutation: MutationType::None,
-            x: x,
-            y: y,
+            x,
+            y,
+            age: 0,
+            dead_for: Self::NEVER_ALIVE,
         };
-        cell.random_mutation(settings); // <--- on appelle la méthode ici
+        cell.random_mutation(settings, rng); // <--- on appelle la méthode ici
         cell
     }
 
-    pub fn change_state(&self, alive_neighbors: usize) -> bool {
+    /// Cellule morte, sans mutation
+    pub fn dead(x: usize, y: usize) -> Self {
+        Self {
+            alive: false,
+            mutation: MutationType::None,
+            x,
+            y,
+            age: 0,
+            dead_for: Self::NEVER_ALIVE,
+        }
+    }
+
+    /// Passe à l’état suivant en tenant l’âge et le temps depuis la mort à jour
+    pub fn advance(&mut self, next_state: bool) {
+        match (self.alive, next_state) {
+            (true, true) => self.age = self.age.saturating_add(1),
+            (false, true) => self.age = 0,
+            (true, false) => self.dead_for = 0,
+            (false, false) => self.dead_for = self.dead_for.saturating_add(1),
+        }
+        self.alive = next_state;
+    }
+
+    /// Prochain état ; les cellules sans mutation suivent la règle de base (Conway par défaut)
+    pub fn change_state(&self, alive_neighbors: usize, base_rule: &Rule) -> bool {
         match self.mutation {
-            MutationType::None => {
-                // Règle classique (Conway)
-                if self.alive {
-                    alive_neighbors == 2 || alive_neighbors == 3
-                } else {
-                    alive_neighbors == 3
-                }
-            }
+            MutationType::None => base_rule.next_state(self.alive, alive_neighbors),
 
             MutationType::Blue => {
                 // 🔵 Mutation "résistante"
@@ -64,27 +117,53 @@ impl Cell {
         }
     }
 
-    /// Tire aléatoirement un type de mutation selon les probabilités
-    pub fn random_mutation(&mut self, settings: &MutationSettings) {
-        let r = random::<f32>();
-        self.mutation = if settings.allow_blue && r < 0.1 {
+    /// Tire aléatoirement un type de mutation selon les probabilités ;
+    /// si leur somme dépasse 1, la rouge n’a que ce que laisse la bleue
+    pub fn random_mutation(&mut self, settings: &MutationSettings, rng: &mut impl Rng) {
+        let r = rng.r#gen::<f32>();
+        // une souche désactivée ne prend pas sa part des tirages
+        let blue = if settings.allow_blue { settings.blue_probability } else { 0.0 };
+        self.mutation = if r < blue {
             MutationType::Blue
-        } else if settings.allow_red && r < 0.2 {
+        } else if settings.allow_red && r < (blue + settings.red_probability).min(1.0) {
             MutationType::Red
         } else {
             MutationType::None
         }
     }
 
-    pub fn cell_color(&self) -> Color {
+    pub fn cell_color(&self, theme: &Theme) -> Color {
         if !self.alive {
-            return Color::BLACK;
+            return theme.ui.dead_cell;
         }
 
-        match self.mutation {
-            MutationType::None => Color::srgb(0.2, 0.8, 0.2), // 🟩 vert
-            MutationType::Blue => Color::srgb(0.2, 0.4, 1.0), // 🔵 bleu
-            MutationType::Red => Color::srgb(1.0, 0.2, 0.2),  // 🔴 rouge
+        theme.strain(self.mutation)
+    }
+
+    /// Couleur affichée selon le mode choisi
+    pub fn display_color(&self, mode: ColorMode, theme: &Theme) -> Color {
+        match mode {
+            ColorMode::Strain => self.cell_color(theme),
+            ColorMode::Age if self.alive => {
+                // échelle logarithmique : les premières générations se distinguent le mieux
+                let t = ((1.0 + self.age as f32).ln() / (1.0 + AGE_SCALE).ln()).min(1.0);
+                let (young, middle, old) = (Srgba::rgb(1.0, 1.0, 0.6), Srgba::rgb(1.0, 0.5, 0.1), Srgba::rgb(0.35, 0.1, 0.55));
+                if t < 0.5 {
+                    young.mix(&middle, t * 2.0).into()
+                } else {
+                    middle.mix(&old, t * 2.0 - 1.0).into()
+                }
+            }
+            ColorMode::Trail if !self.alive && self.dead_for < TRAIL_LENGTH => {
+                // 👣 la couleur de la souche s’éteint peu à peu
+                let fade = 0.5 * (1.0 - (self.dead_for + 1) as f32 / (TRAIL_LENGTH + 1) as f32);
+                theme.ui.dead_cell.mix(&theme.strain(self.mutation), fade)
+            }
+            _ => self.cell_color(theme),
         }
     }
 }
+
+/// Bouton de choix du mode de coloration
+#[derive(Component, Clone, Copy)]
+pub struct ColorModeButton(pub ColorMode);
@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::{
+    cell::Cell,
+    detection::StabilizedEvent,
+    recorder::StatsRecorder,
+    rule::Rule,
+    stats::PopulationHistory
+};
+
+/// Ensemble de cellules vivantes normalisé (translation + symétries)
+pub type Shape = Vec<(i32, i32)>;
+
+/// Objet connu : nom usuel, motif (une ligne par rangée, `O` = vivante) et période
+struct KnownObject {
+    name: &'static str,
+    rows: &'static [&'static str],
+    period: usize,
+}
+
+const KNOWN_OBJECTS: &[KnownObject] = &[
+    // 🧱 Natures mortes
+    KnownObject { name: "block", rows: &["OO", "OO"], period: 1 },
+    KnownObject { name: "beehive", rows: &[".OO.", "O..O", ".OO."], period: 1 },
+    KnownObject { name: "loaf", rows: &[".OO.", "O..O", ".O.O", "..O."], period: 1 },
+    KnownObject { name: "boat", rows: &["OO.", "O.O", ".O."], period: 1 },
+    KnownObject { name: "ship", rows: &["OO.", "O.O", ".OO"], period: 1 },
+    KnownObject { name: "tub", rows: &[".O.", "O.O", ".O."], period: 1 },
+    KnownObject { name: "pond", rows: &[".OO.", "O..O", "O..O", ".OO."], period: 1 },
+    KnownObject { name: "long boat", rows: &["OO..", "O.O.", ".O.O", "..O."], period: 1 },
+    KnownObject { name: "barge", rows: &[".O..", "O.O.", ".O.O", "..O."], period: 1 },
+    // 🔁 Oscillateurs
+    KnownObject { name: "blinker", rows: &["OOO"], period: 2 },
+    KnownObject { name: "toad", rows: &[".OOO", "OOO."], period: 2 },
+    KnownObject { name: "beacon", rows: &["OO..", "OO..", "..OO", "..OO"], period: 2 },
+    // 🚀 Vaisseaux
+    KnownObject { name: "glider", rows: &[".O.", "..O", "OOO"], period: 4 },
+    KnownObject { name: "LWSS", rows: &[".O..O", "O....", "O...O", "OOOO."], period: 4 },
+    KnownObject { name: "MWSS", rows: &[".OOOOO", "O....O", ".....O", "O...O.", "..O..."], period: 4 },
+];
+
+/// Table des formes canoniques de chaque phase des objets connus
+#[derive(Resource)]
+pub struct CensusLibrary {
+    forms: HashMap<Shape, &'static str>,
+}
+
+impl Default for CensusLibrary {
+    fn default() -> Self {
+        let mut forms = HashMap::new();
+        for object in KNOWN_OBJECTS {
+            let mut cells: HashSet<(i32, i32)> = object
+                .rows
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.chars()
+                        .enumerate()
+                        .filter(|(_, c)| *c == 'O')
+                        .map(move |(x, _)| (x as i32, y as i32))
+                })
+                .collect();
+            let first = canonical_form(&cells.iter().copied().collect::<Vec<_>>());
+
+            // chaque phase de l’objet a sa propre forme
+            for _ in 0..object.period {
+                let phase: Vec<_> = cells.iter().copied().collect();
+                forms.insert(canonical_form(&phase), object.name);
+                cells = conway_step(&cells);
+            }
+            debug_assert_eq!(
+                canonical_form(&cells.iter().copied().collect::<Vec<_>>()),
+                first,
+                "{} ne revient pas à sa forme après {} générations",
+                object.name,
+                object.period
+            );
+        }
+        Self { forms }
+    }
+}
+
+/// Résultat d’un recensement : nombre d’objets par nom, triés du plus fréquent au plus rare
+#[derive(Clone, Debug, Default)]
+pub struct Census {
+    pub generation: u64,
+    pub objects: Vec<(String, usize)>,
+}
+
+impl CensusLibrary {
+    /// Sépare les objets et les identifie par leur forme canonique
+    pub fn take_census(&self, generation: u64, alive: &[(i32, i32)]) -> Census {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for component in components(alive) {
+            let name = match self.forms.get(&canonical_form(&component)) {
+                Some(name) => name.to_string(),
+                None => format!("inconnu ({} cellules)", component.len()),
+            };
+            *counts.entry(name).or_default() += 1;
+        }
+
+        let mut objects: Vec<_> = counts.into_iter().collect();
+        objects.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        Census { generation, objects }
+    }
+}
+
+type Transform = fn((i32, i32)) -> (i32, i32);
+
+/// Plus petite représentation parmi les 8 symétries du carré, ramenée à l’origine
+pub fn canonical_form(cells: &[(i32, i32)]) -> Shape {
+    let transforms: [Transform; 8] = [
+        |(x, y)| (x, y),
+        |(x, y)| (-x, y),
+        |(x, y)| (x, -y),
+        |(x, y)| (-x, -y),
+        |(x, y)| (y, x),
+        |(x, y)| (-y, x),
+        |(x, y)| (y, -x),
+        |(x, y)| (-y, -x),
+    ];
+
+    transforms
+        .iter()
+        .map(|t| {
+            let moved: Vec<_> = cells.iter().map(|c| t(*c)).collect();
+            let min_x = moved.iter().map(|c| c.0).min().unwrap_or(0);
+            let min_y = moved.iter().map(|c| c.1).min().unwrap_or(0);
+            let mut shape: Shape = moved.iter().map(|(x, y)| (x - min_x, y - min_y)).collect();
+            shape.sort_unstable();
+            shape
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Regroupe les cellules distantes d’au plus 2 (les phases du crapaud ou de la balise restent d’un seul tenant)
+pub fn components(alive: &[(i32, i32)]) -> Vec<Vec<(i32, i32)>> {
+    let mut remaining: HashSet<(i32, i32)> = alive.iter().copied().collect();
+    let mut groups = Vec::new();
+
+    while let Some(&start) = remaining.iter().next() {
+        remaining.remove(&start);
+        let mut group = vec![start];
+        let mut stack = vec![start];
+        while let Some((x, y)) = stack.pop() {
+            for dy in -2..=2 {
+                for dx in -2..=2 {
+                    if remaining.remove(&(x + dx, y + dy)) {
+                        group.push((x + dx, y + dy));
+                        stack.push((x + dx, y + dy));
+                    }
+                }
+            }
+        }
+        groups.push(group);
+    }
+    groups
+}
+
+// Une génération de Conway sur un plan infini
+fn conway_step(cells: &HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+    let mut neighbors: HashMap<(i32, i32), usize> = HashMap::new();
+    for (x, y) in cells {
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx != 0 || dy != 0 {
+                    *neighbors.entry((x + dx, y + dy)).or_default() += 1;
+                }
+            }
+        }
+    }
+    neighbors
+        .into_iter()
+        .filter(|(pos, n)| Rule::CONWAY.next_state(cells.contains(pos), *n))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Dernier recensement effectué
+#[derive(Resource, Default)]
+pub struct ObjectCensus(pub Option<Census>);
+
+/// Demande de recensement immédiat (bouton)
+#[derive(Event)]
+pub struct CensusRequestEvent;
+
+// Recense à la stabilisation ou à la demande, puis exporte si l’enregistrement est actif
+pub fn census_system(
+    mut ev_stable: EventReader<StabilizedEvent>,
+    mut ev_request: EventReader<CensusRequestEvent>,
+    library: Res<CensusLibrary>,
+    history: Res<PopulationHistory>,
+    query: Query<&Cell>,
+    mut census: ResMut<ObjectCensus>,
+    mut recorder: ResMut<StatsRecorder>,
+) {
+    let stabilized = ev_stable.read().count() > 0;
+    let requested = ev_request.read().count() > 0;
+    if !stabilized && !requested {
+        return;
+    }
+
+    let alive: Vec<(i32, i32)> = query
+        .iter()
+        .filter(|c| c.alive)
+        .map(|c| (c.x as i32, c.y as i32))
+        .collect();
+    let generation = history.latest().map_or(0, |s| s.generation);
+    let result = library.take_census(generation, &alive);
+
+    if let Err(e) = recorder.write_census(&result) {
+        error!("Erreur d’écriture du recensement : {e}");
+    }
+    census.0 = Some(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_form_ignores_position_rotation_and_mirror() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let form = canonical_form(&glider);
+        let moved: Vec<_> = glider.iter().map(|(x, y)| (x + 10, y - 7)).collect();
+        let rotated: Vec<_> = glider.iter().map(|&(x, y)| (-y, x)).collect();
+        let mirrored: Vec<_> = glider.iter().map(|&(x, y)| (-x, y)).collect();
+        assert_eq!(canonical_form(&moved), form);
+        assert_eq!(canonical_form(&rotated), form);
+        assert_eq!(canonical_form(&mirrored), form);
+        assert_ne!(canonical_form(&[(0, 0), (1, 0), (2, 0)]), form);
+        assert!(canonical_form(&[]).is_empty());
+    }
+
+    #[test]
+    fn components_join_cells_up_to_two_apart() {
+        let mut groups = components(&[(0, 0), (2, 2), (10, 10), (13, 10)]);
+        groups.iter_mut().for_each(|g| g.sort_unstable());
+        groups.sort();
+        assert_eq!(groups, vec![vec![(0, 0), (2, 2)], vec![(10, 10)], vec![(13, 10)]]);
+    }
+
+    #[test]
+    fn census_recognizes_every_phase() {
+        let library = CensusLibrary::default();
+        // planeur après une génération, clignotant vertical, bloc
+        let alive = [(0, 1), (2, 1), (1, 2), (2, 2), (1, 3), (20, 0), (20, 1), (20, 2), (30, 30), (31, 30), (30, 31), (31, 31)];
+        let census = library.take_census(7, &alive);
+        let mut objects = census.objects.clone();
+        objects.sort();
+        assert_eq!(census.generation, 7);
+        assert_eq!(objects, vec![("blinker".to_string(), 1), ("block".to_string(), 1), ("glider".to_string(), 1)]);
+        let unknown = library.take_census(0, &[(0, 0)]);
+        assert_eq!(unknown.objects, vec![("inconnu (1 cellules)".to_string(), 1)]);
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use clap::{Args, Parser};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{io, path::PathBuf};
+use crate::{
+    cell::ColorMode,
+    export::{ExportSettings, ImageFormat},
+    generator::Generator,
+    grid::{Boundary, GridConfig, MAX_SIZE},
+    locale::Language,
+    mutation_setting::MutationSettings,
+    rule::Rule,
+    shortcuts::{default_keys_path, KeyBindings},
+    theme::ThemeArgs,
+    systems::{SimRng, StepTimer, DEFAULT_SPEED, MAX_SPEED, MIN_SPEED}
+};
+
+/// Options de simulation communes à l’application et au mode batch
+#[derive(Args, Clone)]
+pub struct SimulationArgs {
+    /// Largeur de la grille
+    #[arg(long, default_value_t = 20, value_parser = parse_size)]
+    pub width: usize,
+    /// Hauteur de la grille
+    #[arg(long, default_value_t = 20, value_parser = parse_size)]
+    pub height: usize,
+    /// Règle de base des cellules sans mutation (ex. B3/S23, B36/S23)
+    #[arg(long)]
+    pub rule: Option<Rule>,
+    /// Comportement aux bords
+    #[arg(long, value_enum, default_value_t = Boundary::Dead)]
+    pub boundary: Boundary,
+    /// Désactive la mutation bleue
+    #[arg(long)]
+    pub no_blue: bool,
+    /// Désactive la mutation rouge
+    #[arg(long)]
+    pub no_red: bool,
+    /// Probabilité qu’une naissance soit bleue
+    #[arg(long, default_value_t = 0.1)]
+    pub blue_prob: f32,
+    /// Probabilité qu’une naissance soit rouge
+    #[arg(long, default_value_t = 0.1)]
+    pub red_prob: f32,
+    #[command(flatten)]
+    pub generator: Generator,
+}
+
+impl SimulationArgs {
+    /// `fallback_rule` : règle du motif chargé, utilisée si `--rule` est absent
+    pub fn grid_config(&self, fallback_rule: Option<Rule>) -> GridConfig {
+        GridConfig {
+            width: self.width.clamp(1, MAX_SIZE),
+            height: self.height.clamp(1, MAX_SIZE),
+            boundary: self.boundary,
+            rule: self.rule.or(fallback_rule).unwrap_or_default(),
+        }
+    }
+
+    pub fn mutation_settings(&self) -> MutationSettings {
+        MutationSettings {
+            allow_blue: !self.no_blue,
+            allow_red: !self.no_red,
+            blue_probability: self.blue_prob.clamp(0.0, 1.0),
+            red_probability: self.red_prob.clamp(0.0, 1.0),
+        }
+    }
+}
+
+fn parse_size(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(size) if (1..=MAX_SIZE).contains(&size) => Ok(size),
+        _ => Err(format!("taille attendue entre 1 et {MAX_SIZE}")),
+    }
+}
+
+/// Ligne de commande de l’application interactive
+#[derive(Parser)]
+#[command(about = "Jeu de la vie avec mutations")]
+pub struct Cli {
+    #[command(flatten)]
+    pub simulation: SimulationArgs,
+    /// Graine aléatoire (partie reproductible)
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Motif initial (.rle, .cells) placé au centre d’une grille vide
+    #[arg(long)]
+    pub pattern: Option<PathBuf>,
+    /// Durée d’une étape en secondes
+    #[arg(long, default_value_t = DEFAULT_SPEED)]
+    pub speed: f32,
+    /// Démarre en pause
+    #[arg(long)]
+    pub paused: bool,
+    /// Enregistre les statistiques en CSV (nom automatique si aucun fichier n’est donné)
+    #[arg(long)]
+    pub record: Option<Option<PathBuf>>,
+    /// Dossier de motifs personnels, surveillé pendant l’exécution
+    #[arg(long)]
+    pub patterns_dir: Option<PathBuf>,
+    #[command(flatten)]
+    pub appearance: ThemeArgs,
+    /// Langue de l’interface (celle du système par défaut)
+    #[arg(long, value_enum)]
+    pub lang: Option<Language>,
+    /// Coloration des cellules : souche, âge ou traînée des cellules mortes
+    #[arg(long, value_enum, default_value_t = ColorMode::Strain)]
+    pub color_mode: ColorMode,
+    /// Pixels par cellule dans les images exportées
+    #[arg(long, default_value_t = 10)]
+    pub export_scale: u32,
+    /// Durée d’une image des animations exportées, en millisecondes
+    #[arg(long, default_value_t = 100)]
+    pub frame_delay: u32,
+    /// Nombre de générations capturées par « Animation »
+    #[arg(long, default_value_t = 100)]
+    pub animation_frames: usize,
+    /// Format des animations exportées (gif ou apng)
+    #[arg(long, value_enum, default_value_t = ImageFormat::Gif)]
+    pub animation_format: ImageFormat,
+    /// Fichier JSON de raccourcis clavier (par défaut keys.json du dossier de configuration, s’il existe)
+    #[arg(long)]
+    pub keys: Option<PathBuf>,
+    /// Script Rhai exécuté une fois la grille créée
+    #[arg(long)]
+    pub script: Option<PathBuf>,
+    /// Serveur de contrôle local : HTTP et WebSocket sur 127.0.0.1 (port 7878 si aucun n’est donné)
+    #[arg(long)]
+    pub server: Option<Option<u16>>,
+}
+
+impl Cli {
+    pub fn key_bindings(&self) -> io::Result<KeyBindings> {
+        match self.keys.clone().or_else(|| default_keys_path().filter(|path| path.exists())) {
+            Some(path) => KeyBindings::load(&path),
+            None => Ok(KeyBindings::default()),
+        }
+    }
+
+    pub fn step_timer(&self) -> StepTimer {
+        let speed = self.speed.clamp(MAX_SPEED, MIN_SPEED);
+        StepTimer {
+            timer: Timer::from_seconds(speed, TimerMode::Repeating),
+            speed,
+            paused: self.paused,
+        }
+    }
+
+    pub fn export_settings(&self) -> ExportSettings {
+        ExportSettings {
+            scale: self.export_scale.max(1),
+            frame_delay_ms: self.frame_delay,
+            frames: self.animation_frames.max(1),
+            // une animation n’est jamais un PNG fixe
+            animation_format: match self.animation_format {
+                ImageFormat::Png => ImageFormat::Apng,
+                format => format,
+            },
+        }
+    }
+
+    pub fn rng(&self) -> SimRng {
+        SimRng(match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        })
+    }
+}
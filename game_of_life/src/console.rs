@@ -0,0 +1,398 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+use crate::{
+    file_drop::OpenFileEvent,
+    grid::GridConfig,
+    mutation_setting::MutationSettings,
+    rule::Rule,
+    scripting::ScriptEvent,
+    shortcuts::{Action, KeyBindings, Modifiers},
+    stats::PopulationHistory,
+    systems::{SimRng, StepTimer, MAX_SPEED, MIN_SPEED},
+    theme::Theme,
+    toast::Toast,
+    widgets::{text_field::{edit, Edit}, WidgetFocus},
+    locale::Localization
+};
+
+/// Lignes gardées dans le journal
+const LOG_LEN: usize = 200;
+/// Lignes du journal affichées au-dessus de la saisie
+const VISIBLE_LINES: usize = 14;
+
+/// Commandes, dans l’ordre de l’aide (« console-help-<nom> »)
+const COMMANDS: [&str; 10] = ["step", "rule", "load", "seed", "set", "stats", "run", "eval", "clear", "help"];
+/// Réglages de `set`
+const SETTINGS: [&str; 5] = ["blue", "red", "blue.prob", "red.prob", "speed"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineKind {
+    /// Commande saisie, rappelée dans le journal
+    Command,
+    Output,
+    Error,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConsoleLine {
+    pub text: String,
+    pub kind: LineKind,
+}
+
+/// Console déroulante : saisie, historique des commandes (flèches), complétion (Tab) et journal
+#[derive(Resource, Default)]
+pub struct Console {
+    pub open: bool,
+    pub input: String,
+    pub log: VecDeque<ConsoleLine>,
+    history: Vec<String>,
+    /// Commande de l’historique affichée pendant qu’on le parcourt
+    browsing: Option<usize>,
+}
+
+/// Ligne validée par Entrée
+#[derive(Event, Clone, Debug)]
+pub struct ConsoleCommand(pub String);
+
+#[derive(Component)]
+pub struct ConsolePanel;
+
+#[derive(Component)]
+pub struct ConsoleLog;
+
+#[derive(Component)]
+pub struct ConsolePrompt;
+
+impl Console {
+    pub fn print(&mut self, text: impl Into<String>, kind: LineKind) {
+        if self.log.len() == LOG_LEN {
+            self.log.pop_front();
+        }
+        self.log.push_back(ConsoleLine { text: text.into(), kind });
+    }
+
+    /// Ligne saisie, retenue dans l’historique et rappelée dans le journal
+    fn submit(&mut self) -> Option<String> {
+        let line = std::mem::take(&mut self.input).trim().to_string();
+        self.browsing = None;
+        if line.is_empty() {
+            return None;
+        }
+        if self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+        }
+        self.print(format!("> {line}"), LineKind::Command);
+        Some(line)
+    }
+
+    /// Commande précédente (`older`) ou suivante de l’historique ; après la plus récente, saisie vide
+    fn browse(&mut self, older: bool) {
+        let last = self.history.len().checked_sub(1);
+        self.browsing = match (self.browsing, older) {
+            (None, true) => last,
+            (None, false) => None,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) => Some(i + 1).filter(|i| *i < self.history.len()),
+        };
+        self.input = self.browsing.map(|i| self.history[i].clone()).unwrap_or_default();
+    }
+
+    /// Complète le dernier mot (commande, réglage de `set`, fichier de `load` et `run`) ;
+    /// renvoie les possibilités quand il y en a plusieurs
+    fn complete(&mut self) -> Vec<String> {
+        let split = self.input.rfind(' ').map_or(0, |i| i + 1);
+        let (head, word) = (self.input[..split].to_string(), self.input[split..].to_string());
+        let words: Vec<&str> = head.split_whitespace().collect();
+        let candidates: Vec<String> = match words.as_slice() {
+            [] => COMMANDS.iter().map(|c| c.to_string()).collect(),
+            ["set"] => SETTINGS.iter().map(|s| s.to_string()).collect(),
+            ["load" | "run"] => path_candidates(&word),
+            _ => Vec::new(),
+        };
+        let mut matching: Vec<String> = candidates.into_iter().filter(|c| c.starts_with(&word)).collect();
+        matching.sort();
+
+        match matching.as_slice() {
+            [] => Vec::new(),
+            [only] => {
+                self.input = format!("{head}{only}");
+                // un dossier se complète encore
+                if !only.ends_with('/') {
+                    self.input.push(' ');
+                }
+                Vec::new()
+            }
+            several => {
+                let mut prefix = several[0].clone();
+                for candidate in &several[1..] {
+                    while !candidate.starts_with(&prefix) {
+                        prefix.pop();
+                    }
+                }
+                self.input = format!("{head}{prefix}");
+                matching
+            }
+        }
+    }
+}
+
+/// Fichiers et dossiers (terminés par « / ») qui prolongent le chemin `word`
+fn path_candidates(word: &str) -> Vec<String> {
+    let (dir, shown) = match word.rfind('/') {
+        Some(i) => (&word[..=i], &word[..=i]),
+        None => ("./", ""),
+    };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| {
+            let mut name = format!("{shown}{}", entry.file_name().to_string_lossy());
+            if entry.path().is_dir() {
+                name.push('/');
+            }
+            name
+        })
+        .collect()
+}
+
+fn parse_flag(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "on" | "true" | "1" | "yes" => Some(true),
+        "off" | "false" | "0" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+// `NaN` et `inf` sont des `f32` valides pour `parse`, mais pas des réglages
+fn parse_number(value: &str) -> Option<f32> {
+    value.parse::<f32>().ok().filter(|v| v.is_finite())
+}
+
+// ⌨️ La touche de la console l’ouvre et lui donne le clavier ; ouverte, elle reçoit la saisie
+// jusqu’à Échap ou la même touche
+#[allow(clippy::too_many_arguments)]
+pub fn console_input_system(
+    mut keyboard: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut actions: EventReader<Action>,
+    panels: Query<Entity, With<ConsolePanel>>,
+    mut focus: ResMut<WidgetFocus>,
+    mut console: ResMut<Console>,
+    mut ev_command: EventWriter<ConsoleCommand>,
+) {
+    let Ok(panel) = panels.get_single() else {
+        return;
+    };
+    if actions.read().any(|action| *action == Action::Console) {
+        console.open = true;
+        focus.focused = Some(panel);
+        // la touche qui vient d’ouvrir la console n’est pas saisie
+        keyboard.clear();
+        return;
+    }
+    if !console.open || focus.focused != Some(panel) {
+        keyboard.clear();
+        return;
+    }
+
+    let modifiers = Modifiers::current(&keys);
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        if bindings.keys(Action::Console).iter().any(|b| b.matches(&input.logical_key, modifiers)) {
+            console.open = false;
+            focus.focused = None;
+            break;
+        }
+        match &input.logical_key {
+            Key::ArrowUp => console.browse(true),
+            Key::ArrowDown => console.browse(false),
+            Key::Tab => {
+                let candidates = console.complete();
+                if !candidates.is_empty() {
+                    console.print(candidates.join("  "), LineKind::Output);
+                }
+            }
+            key => match edit(&mut console.input, key, 0, |_| true) {
+                Edit::Submit => {
+                    if let Some(line) = console.submit() {
+                        ev_command.send(ConsoleCommand(line));
+                    }
+                }
+                Edit::Cancel => {
+                    console.open = false;
+                    focus.focused = None;
+                }
+                Edit::Changed | Edit::Ignored => {}
+            },
+        }
+    }
+}
+
+// ▶️ Commandes de la console ; `step`, `run` et `eval` passent par les scripts, `load` par l’ouverture de fichiers
+#[allow(clippy::too_many_arguments)]
+pub fn console_command_system(
+    mut commands: EventReader<ConsoleCommand>,
+    mut console: ResMut<Console>,
+    mut config: ResMut<GridConfig>,
+    mut settings: ResMut<MutationSettings>,
+    mut timer: ResMut<StepTimer>,
+    mut rng: ResMut<SimRng>,
+    history: Res<PopulationHistory>,
+    loc: Res<Localization>,
+    mut ev_open: EventWriter<OpenFileEvent>,
+    mut ev_script: EventWriter<ScriptEvent>,
+) {
+    for ConsoleCommand(line) in commands.read() {
+        let (name, args) = line.split_once(' ').map_or((line.as_str(), ""), |(name, args)| (name, args.trim()));
+        // mauvais arguments : rappel de la syntaxe
+        let usage = loc.t(&format!("console-help-{name}"));
+        let output = match name {
+            "step" => match if args.is_empty() { Ok(1) } else { args.parse::<u64>() } {
+                Ok(n) => {
+                    ev_script.send(ScriptEvent::Run(format!("step({n})")));
+                    None
+                }
+                Err(_) => Some(Err(usage)),
+            },
+            "rule" if args.is_empty() => Some(Ok(loc.t_with("console-rule", &[("rule", config.rule.to_string().into())]))),
+            "rule" => match args.parse::<Rule>() {
+                Ok(rule) => {
+                    config.rule = rule;
+                    Some(Ok(loc.t_with("console-rule", &[("rule", rule.to_string().into())])))
+                }
+                Err(e) => Some(Err(e.to_string())),
+            },
+            "load" | "run" if args.is_empty() => Some(Err(usage)),
+            "load" => {
+                ev_open.send(OpenFileEvent(PathBuf::from(args)));
+                None
+            }
+            "run" => {
+                ev_script.send(ScriptEvent::RunFile(PathBuf::from(args)));
+                None
+            }
+            "eval" if args.is_empty() => Some(Err(usage)),
+            "eval" => {
+                ev_script.send(ScriptEvent::Run(args.to_string()));
+                None
+            }
+            "seed" => match args.parse::<u64>() {
+                Ok(seed) => {
+                    rng.0 = StdRng::seed_from_u64(seed);
+                    Some(Ok(loc.t_with("console-seed", &[("seed", seed.to_string().into())])))
+                }
+                Err(_) => Some(Err(usage)),
+            },
+            "set" => {
+                let (key, value) = args.split_once(' ').map_or((args, ""), |(key, value)| (key, value.trim()));
+                let applied = match key {
+                    "blue" => parse_flag(value).map(|on| settings.allow_blue = on),
+                    "red" => parse_flag(value).map(|on| settings.allow_red = on),
+                    "blue.prob" => parse_number(value).map(|p| settings.blue_probability = p.clamp(0.0, 1.0)),
+                    "red.prob" => parse_number(value).map(|p| settings.red_probability = p.clamp(0.0, 1.0)),
+                    "speed" => parse_number(value).map(|s| timer.set_speed(s.clamp(MAX_SPEED, MIN_SPEED))),
+                    _ => None,
+                };
+                Some(match applied {
+                    Some(()) => Ok(loc.t_with("console-set", &[("setting", key.to_string().into()), ("value", value.to_string().into())])),
+                    None => Err(usage),
+                })
+            }
+            "stats" => history.latest().map(|stats| {
+                Ok(loc.t_with(
+                    "console-stats",
+                    &[
+                        ("generation", stats.generation.into()),
+                        ("total", stats.total.into()),
+                        ("normal", stats.normal.into()),
+                        ("blue", stats.blue.into()),
+                        ("red", stats.red.into()),
+                    ],
+                ))
+            }),
+            "clear" => {
+                console.log.clear();
+                None
+            }
+            "help" => {
+                for command in COMMANDS {
+                    console.print(loc.t(&format!("console-help-{command}")), LineKind::Output);
+                }
+                None
+            }
+            _ => Some(Err(loc.t_with("console-unknown", &[("command", name.to_string().into())]))),
+        };
+        match output {
+            Some(Ok(text)) => console.print(text, LineKind::Output),
+            Some(Err(text)) => console.print(text, LineKind::Error),
+            None => {}
+        }
+    }
+}
+
+// 📋 Les notifications (fichier chargé, sortie ou erreur d’un script…) s’ajoutent au journal
+pub fn console_log_system(mut toasts: EventReader<Toast>, mut console: ResMut<Console>) {
+    for toast in toasts.read() {
+        let kind = if toast.error { LineKind::Error } else { LineKind::Output };
+        console.print(toast.message.clone(), kind);
+    }
+}
+
+// Console affichée ou masquée, dernières lignes du journal, saisie avec un curseur quand elle a le clavier
+#[allow(clippy::type_complexity)]
+pub fn update_console(
+    console: Res<Console>,
+    focus: Res<WidgetFocus>,
+    theme: Res<Theme>,
+    mut panels: Query<(Entity, &mut Visibility), With<ConsolePanel>>,
+    mut logs: Query<&mut Text, (With<ConsoleLog>, Without<ConsolePrompt>)>,
+    mut prompts: Query<&mut Text, With<ConsolePrompt>>,
+) {
+    if !console.is_changed() && !focus.is_changed() && !theme.is_changed() {
+        return;
+    }
+    let Ok((panel, mut visibility)) = panels.get_single_mut() else {
+        return;
+    };
+    let shown = if console.open { Visibility::Inherited } else { Visibility::Hidden };
+    if *visibility != shown {
+        *visibility = shown;
+    }
+
+    for mut text in &mut logs {
+        let style = text.sections[0].style.clone();
+        let start = console.log.len().saturating_sub(VISIBLE_LINES);
+        let lines: Vec<TextSection> = console
+            .log
+            .iter()
+            .skip(start)
+            .enumerate()
+            .map(|(i, line)| {
+                let color = match line.kind {
+                    LineKind::Command => theme.ui.button_active,
+                    LineKind::Output => theme.ui.text,
+                    LineKind::Error => theme.ui.button_alert,
+                };
+                let separator = if i == 0 { "" } else { "\n" };
+                TextSection::new(format!("{separator}{}", line.text), TextStyle { color, ..style.clone() })
+            })
+            .collect();
+        // toujours au moins une section : elle garde la police pour la prochaine fois
+        text.sections = if lines.is_empty() { vec![TextSection::new("", style)] } else { lines };
+    }
+    for mut text in &mut prompts {
+        let cursor = if focus.focused == Some(panel) { "|" } else { "" };
+        text.sections[0].value = format!("> {}{cursor}", console.input);
+        text.sections[0].style.color = theme.ui.text;
+    }
+}
@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::{
+    interface::ResetGridEvent,
+    stats::{GenerationStats, PopulationHistory},
+    systems::StepTimer
+};
+
+/// Plus longue période recherchée
+pub const MAX_PERIOD: usize = 64;
+
+/// Nature de l’état final détecté
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StabilizationKind {
+    Empty,
+    Static,
+    Periodic,
+}
+
+/// Résultat de la détection : type, période et génération de détection
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stabilization {
+    pub kind: StabilizationKind,
+    pub period: usize,
+    pub generation: u64,
+}
+
+#[derive(Event)]
+pub struct StabilizedEvent(pub Stabilization);
+
+/// Réaction de l’application quand la grille se stabilise
+#[derive(Resource, Default)]
+pub struct StabilizationSettings {
+    pub auto_pause: bool,
+    pub auto_reset: bool,
+}
+
+#[derive(Component)]
+pub enum StabilizationCheckbox {
+    AutoPause,
+    AutoReset,
+}
+
+/// Détecte une grille vide, figée ou périodique à partir des empreintes récentes
+///
+/// Une période P n’est retenue que si les P dernières générations répètent
+/// toutes celles d’une période plus tôt : une coïncidence isolée (par exemple
+/// une cellule qui renaît avec une autre mutation) ne suffit pas.
+#[derive(Resource, Default)]
+pub struct CycleDetector {
+    hashes: VecDeque<u64>,
+    detected: Option<Stabilization>,
+}
+
+impl CycleDetector {
+    pub fn reset(&mut self) {
+        self.hashes.clear();
+        self.detected = None;
+    }
+
+    /// Résultat déjà détecté pour la partie en cours
+    pub fn detected(&self) -> Option<Stabilization> {
+        self.detected
+    }
+
+    /// Ajoute une génération ; renvoie `Some` uniquement la première fois qu’un état final est reconnu
+    pub fn observe(&mut self, stats: &GenerationStats) -> Option<Stabilization> {
+        if self.detected.is_some() {
+            return None;
+        }
+
+        if self.hashes.len() == 2 * MAX_PERIOD {
+            self.hashes.pop_front();
+        }
+        self.hashes.push_back(stats.state_hash);
+
+        let found = if stats.total == 0 {
+            Some((StabilizationKind::Empty, 1))
+        } else {
+            (1..=MAX_PERIOD).find(|&p| self.repeats_with_period(p)).map(|p| {
+                let kind = if p == 1 { StabilizationKind::Static } else { StabilizationKind::Periodic };
+                (kind, p)
+            })
+        };
+
+        self.detected = found.map(|(kind, period)| Stabilization {
+            kind,
+            period,
+            generation: stats.generation,
+        });
+        self.detected
+    }
+
+    fn repeats_with_period(&self, period: usize) -> bool {
+        let n = self.hashes.len();
+        n >= 2 * period && (n - period..n).all(|i| self.hashes[i] == self.hashes[i - period])
+    }
+}
+
+// Suit l’historique de population et émet un évènement à la stabilisation
+pub fn detect_stabilization_system(
+    history: Res<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut ev_stable: EventWriter<StabilizedEvent>,
+) {
+    if !history.is_changed() {
+        return;
+    }
+    let Some(stats) = history.latest() else {
+        return;
+    };
+
+    // génération 0 : nouvelle grille, on repart de zéro
+    if stats.generation == 0 {
+        detector.reset();
+    }
+    if let Some(stabilization) = detector.observe(stats) {
+        ev_stable.send(StabilizedEvent(stabilization));
+    }
+}
+
+// Pause ou relance automatique selon les réglages
+pub fn stabilization_response_system(
+    mut ev_stable: EventReader<StabilizedEvent>,
+    settings: Res<StabilizationSettings>,
+    mut timer: ResMut<StepTimer>,
+    mut ev_reset: EventWriter<ResetGridEvent>,
+) {
+    for StabilizedEvent(stabilization) in ev_stable.read() {
+        info!(
+            "Grille stabilisée : {:?}, période {} (génération {})",
+            stabilization.kind, stabilization.period, stabilization.generation
+        );
+        if settings.auto_reset {
+            ev_reset.send(ResetGridEvent);
+        } else if settings.auto_pause {
+            timer.paused = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use crate::{
+        grid::{Grid, GridConfig},
+        mutation_setting::MutationSettings,
+        pattern::Pattern
+    };
+
+    fn stats(generation: u64, state_hash: u64) -> GenerationStats {
+        GenerationStats { generation, total: 1, state_hash, ..Default::default() }
+    }
+
+    #[test]
+    fn detects_empty_grid_at_once() {
+        let mut detector = CycleDetector::default();
+        let found = detector.observe(&GenerationStats { generation: 5, ..Default::default() });
+        assert_eq!(found, Some(Stabilization { kind: StabilizationKind::Empty, period: 1, generation: 5 }));
+        // signalé une seule fois
+        assert_eq!(detector.observe(&GenerationStats::default()), None);
+        assert_eq!(detector.detected().map(|s| s.kind), Some(StabilizationKind::Empty));
+    }
+
+    #[test]
+    fn needs_a_full_repeated_period() {
+        let mut detector = CycleDetector::default();
+        // 1 2 3 1 : une coïncidence, pas encore une période 3
+        for (generation, hash) in [1, 2, 3, 1].into_iter().enumerate() {
+            assert_eq!(detector.observe(&stats(generation as u64, hash)), None);
+        }
+        assert_eq!(detector.observe(&stats(4, 2)), None);
+        let found = detector.observe(&stats(5, 3)).unwrap();
+        assert_eq!((found.kind, found.period, found.generation), (StabilizationKind::Periodic, 3, 5));
+    }
+
+    #[test]
+    fn reset_forgets_history() {
+        let mut detector = CycleDetector::default();
+        detector.observe(&stats(0, 7));
+        assert_eq!(detector.observe(&stats(1, 7)).map(|s| s.kind), Some(StabilizationKind::Static));
+        detector.reset();
+        assert_eq!(detector.detected(), None);
+        assert_eq!(detector.observe(&stats(2, 7)), None);
+    }
+
+    #[test]
+    fn blinker_has_period_two() {
+        let config = GridConfig { width: 10, height: 10, ..Default::default() };
+        let mut grid = Grid::with_pattern(&config, &Pattern::parse_rle("3o!").unwrap());
+        let settings = MutationSettings { allow_blue: false, allow_red: false, ..Default::default() };
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut detector = CycleDetector::default();
+        let found = (0..10).find_map(|_| detector.observe(&grid.step(&settings, &mut rng))).unwrap();
+        assert_eq!((found.kind, found.period), (StabilizationKind::Periodic, 2));
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashSet;
+use crate::{
+    camera::{grid_viewport_rect, pointer_over_ui, screen_to_world, GridCamera},
+    cell::Cell,
+    detection::CycleDetector,
+    edits::EditHistory,
+    generator::Symmetry,
+    grid::{Grid, GridConfig},
+    selection::Selection,
+    stats::PopulationHistory,
+    systems::world_to_cell
+};
+
+/// Mode dessin : clic gauche pour donner vie aux cellules, clic droit pour les effacer ;
+/// chaque trait est reproduit par la symétrie choisie autour du centre de la grille
+#[derive(Resource)]
+pub struct DrawSettings {
+    pub enabled: bool,
+    pub symmetry: Symmetry,
+}
+
+impl Default for DrawSettings {
+    fn default() -> Self {
+        Self { enabled: false, symmetry: Symmetry::C1 }
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum DrawControl {
+    Enabled,
+    Symmetry,
+}
+
+/// Trait en cours : grille d’avant le trait (une seule annulation pour tout le trait)
+pub struct Stroke {
+    before: Grid,
+    last: (usize, usize),
+    alive: bool,
+}
+
+// ✏️ Dessin à la souris, symétrique ; sans effet quand un motif attend d’être collé
+#[allow(clippy::too_many_arguments)]
+pub fn draw_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Transform, &OrthographicProjection), With<GridCamera>>,
+    interactions: Query<&Interaction>,
+    config: Res<GridConfig>,
+    draw: Res<DrawSettings>,
+    selection: Res<Selection>,
+    mut cells: Query<&mut Cell>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut edits: ResMut<EditHistory>,
+    mut stroke: Local<Option<Stroke>>,
+) {
+    let pressed = draw.enabled && (buttons.pressed(MouseButton::Left) || buttons.pressed(MouseButton::Right));
+    if !pressed {
+        // fin du trait : annulable d’un coup, statistiques à jour
+        if let Some(stroke) = stroke.take() {
+            let generation = history.latest().map_or(0, |s| s.generation);
+            let after = Grid::from_cells(&config, generation, cells.iter().copied());
+            if after.cells != stroke.before.cells {
+                edits.record(stroke.before, &after);
+                history.replace_latest(after.stats());
+                detector.reset();
+            }
+        }
+        return;
+    }
+
+    let (Ok(window), Ok((transform, projection))) = (windows.get_single(), cameras.get_single()) else {
+        return;
+    };
+    let Some((x, y)) = window
+        .cursor_position()
+        .filter(|c| grid_viewport_rect(window).contains(*c))
+        .map(|c| world_to_cell(config.width, config.height, screen_to_world(window, transform, projection.scale, c)))
+    else {
+        return;
+    };
+    if x < 0 || y < 0 || x as usize >= config.width || y as usize >= config.height {
+        return;
+    }
+    let cell = (x as usize, y as usize);
+
+    // un trait commence dans la grille, hors du panneau, et jamais pendant un collage
+    let start = match stroke.as_ref() {
+        Some(stroke) => stroke.last,
+        None => {
+            let just_pressed = buttons.just_pressed(MouseButton::Left) || buttons.just_pressed(MouseButton::Right);
+            if !just_pressed || pointer_over_ui(&interactions) || selection.floating.is_some() {
+                return;
+            }
+            let generation = history.latest().map_or(0, |s| s.generation);
+            *stroke = Some(Stroke {
+                before: Grid::from_cells(&config, generation, cells.iter().copied()),
+                last: cell,
+                alive: buttons.pressed(MouseButton::Left),
+            });
+            cell
+        }
+    };
+    let Some(stroke) = stroke.as_mut() else {
+        return;
+    };
+
+    // toutes les cellules du segment depuis la position précédente, et leurs images
+    let targets: HashSet<(usize, usize)> = line(start, cell)
+        .into_iter()
+        .flat_map(|(x, y)| draw.symmetry.images(x, y, config.width, config.height))
+        .collect();
+    for mut c in &mut cells {
+        if targets.contains(&(c.x, c.y)) && c.alive != stroke.alive {
+            *c = Cell { alive: stroke.alive, ..Cell::dead(c.x, c.y) };
+        }
+    }
+    stroke.last = cell;
+}
+
+/// Cellules du segment de `a` à `b` (Bresenham), extrémités comprises
+fn line(a: (usize, usize), b: (usize, usize)) -> Vec<(usize, usize)> {
+    let (mut x, mut y) = (a.0 as i64, a.1 as i64);
+    let (x1, y1) = (b.0 as i64, b.1 as i64);
+    let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+    let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+    let mut error = dx + dy;
+    let mut cells = vec![(x as usize, y as usize)];
+    while (x, y) != (x1, y1) {
+        let e2 = 2 * error;
+        if e2 >= dy {
+            error += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            error += dx;
+            y += sy;
+        }
+        cells.push((x as usize, y as usize));
+    }
+    cells
+}
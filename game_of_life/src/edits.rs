@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use crate::{
+    camera::ViewEvent,
+    cell::Cell,
+    detection::CycleDetector,
+    grid::{Grid, GridConfig},
+    selection::Selection,
+    shortcuts::Action,
+    stats::PopulationHistory,
+    systems::{apply_grid, replace_grid}
+};
+
+/// Nombre de modifications qu’on peut annuler
+const UNDO_LIMIT: usize = 100;
+/// Cellules gardées au plus dans `undo` et `redo` (≈ 250 Mo) : sur une grande grille,
+/// les plus anciennes modifications sont oubliées plus tôt
+const MAX_STORED_CELLS: usize = 8_000_000;
+
+/// Grilles d’avant chaque modification (sélection, collage, dépôt de fichier, nouvelle grille…),
+/// et grille de la génération 0 pour revenir au départ
+#[derive(Resource, Default)]
+pub struct EditHistory {
+    undo: Vec<Grid>,
+    redo: Vec<Grid>,
+    start: Option<Grid>,
+}
+
+impl EditHistory {
+    /// Grille affichée au lancement
+    pub fn set_start(&mut self, grid: Grid) {
+        self.start = Some(grid);
+    }
+
+    /// `before` devient annulable ; une grille modifiée en génération 0 devient le nouveau départ
+    pub fn record(&mut self, before: Grid, after: &Grid) {
+        if self.undo.len() == UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+        self.undo.push(before);
+        self.redo.clear();
+        self.trim();
+        if after.generation == 0 {
+            self.start = Some(after.clone());
+        }
+    }
+
+    /// Oublie les grilles les plus éloignées au-delà de `MAX_STORED_CELLS`, en gardant la dernière de chaque pile
+    fn trim(&mut self) {
+        let mut stored: usize = self.undo.iter().chain(&self.redo).map(|grid| grid.cells.len()).sum();
+        while stored > MAX_STORED_CELLS && self.undo.len() > 1 {
+            stored -= self.undo.remove(0).cells.len();
+        }
+        while stored > MAX_STORED_CELLS && self.redo.len() > 1 {
+            stored -= self.redo.remove(0).cells.len();
+        }
+    }
+
+    /// Grille à rétablir ; `current` pourra être rétablie par `redo`
+    fn undo(&mut self, current: Grid) -> Option<Grid> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.trim();
+        Some(previous)
+    }
+
+    fn redo(&mut self, current: Grid) -> Option<Grid> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.trim();
+        Some(next)
+    }
+}
+
+// ↩️ Annuler / rétablir, vider la grille, revenir à la génération 0
+#[allow(clippy::too_many_arguments)]
+pub fn edit_action_system(
+    mut actions: EventReader<Action>,
+    mut commands: Commands,
+    cell_entities: Query<Entity, With<Cell>>,
+    mut cells: Query<&mut Cell>,
+    mut config: ResMut<GridConfig>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut edits: ResMut<EditHistory>,
+    mut selection: ResMut<Selection>,
+    mut ev_view: EventWriter<ViewEvent>,
+) {
+    for action in actions.read() {
+        let generation = history.latest().map_or(0, |s| s.generation);
+        let current = Grid::from_cells(&config, generation, cells.iter().copied());
+        let grid = match action {
+            Action::Undo => edits.undo(current),
+            Action::Redo => edits.redo(current),
+            Action::Clear => {
+                let empty = Grid::empty(&config);
+                edits.record(current, &empty);
+                Some(empty)
+            }
+            Action::Reset => edits.start.clone().inspect(|start| edits.record(current, start)),
+            _ => None,
+        };
+        let Some(grid) = grid else {
+            continue;
+        };
+        if grid.generation == 0 {
+            edits.start = Some(grid.clone());
+        }
+
+        if (grid.width, grid.height) == (config.width, config.height) {
+            // mêmes dimensions : seules les cellules changent, l’historique suit s’il s’agit de la même génération
+            apply_grid(&grid, &mut cells);
+            if grid.generation == generation {
+                history.replace_latest(grid.stats());
+            } else {
+                history.clear();
+                history.push(grid.stats());
+            }
+        } else {
+            // grille agrandie par un dépôt de fichier, ou session d’une autre taille
+            *config = GridConfig { width: grid.width, height: grid.height, boundary: grid.boundary, rule: grid.rule };
+            replace_grid(&mut commands, cell_entities.iter(), &grid, &mut history);
+            selection.region = None;
+            ev_view.send(ViewEvent::Reset);
+        }
+        detector.reset();
+    }
+}
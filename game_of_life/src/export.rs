@@ -0,0 +1,355 @@
+use bevy::prelude::*;
+use clap::ValueEnum;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::{
+    cell::Cell,
+    grid::{Grid, GridConfig},
+    stats::PopulationHistory,
+    theme::Theme,
+    toast::Toast,
+    locale::Localization
+};
+
+/// Pixels au plus dans une image exportée (≈ 300 Mo en RGB)
+const MAX_PIXELS: u64 = 100_000_000;
+
+/// Format des fichiers image
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ImageFormat {
+    Png,
+    Gif,
+    Apng,
+}
+
+impl ImageFormat {
+    /// Format explicite, sinon déduit de l’extension (PNG par défaut)
+    pub fn resolve(format: Option<ImageFormat>, path: &Path) -> ImageFormat {
+        format.unwrap_or(match path.extension().map(|e| e.to_string_lossy().to_ascii_lowercase()).as_deref() {
+            Some("gif") => ImageFormat::Gif,
+            Some("apng") => ImageFormat::Apng,
+            _ => ImageFormat::Png,
+        })
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Apng => "apng",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    TooLarge(u32, u32),
+    NoFrames,
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportError::Io(e) => write!(f, "écriture impossible : {e}"),
+            ExportError::Png(e) => write!(f, "encodage PNG : {e}"),
+            ExportError::Gif(e) => write!(f, "encodage GIF : {e}"),
+            ExportError::TooLarge(w, h) => write!(f, "image trop grande ({w} × {h} pixels)"),
+            ExportError::NoFrames => write!(f, "aucune image à enregistrer"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(e: io::Error) -> Self {
+        ExportError::Io(e)
+    }
+}
+
+impl From<png::EncodingError> for ExportError {
+    fn from(e: png::EncodingError) -> Self {
+        ExportError::Png(e)
+    }
+}
+
+impl From<gif::EncodingError> for ExportError {
+    fn from(e: gif::EncodingError) -> Self {
+        ExportError::Gif(e)
+    }
+}
+
+/// Couleurs d’une génération, une par cellule (celles de `Cell::cell_color` dans le thème)
+#[derive(Clone)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    pub colors: Vec<[u8; 3]>,
+}
+
+impl Frame {
+    pub fn from_grid(grid: &Grid, theme: &Theme) -> Frame {
+        let colors = grid
+            .cells
+            .iter()
+            .map(|cell| {
+                let [r, g, b, _] = cell.cell_color(theme).to_srgba().to_u8_array();
+                [r, g, b]
+            })
+            .collect();
+        Frame { width: grid.width, height: grid.height, colors }
+    }
+
+    /// Taille en pixels avec `scale` pixels par cellule (PNG : 2³¹ − 1 au plus par côté),
+    /// vérifiée avant d’allouer l’image entière
+    fn size(&self, scale: u32) -> Result<(u32, u32), ExportError> {
+        let (w, h) = (self.width as u64 * scale as u64, self.height as u64 * scale as u64);
+        if w == 0 || h == 0 || w > i32::MAX as u64 || h > i32::MAX as u64 || w * h > MAX_PIXELS {
+            return Err(ExportError::TooLarge(w.min(u32::MAX as u64) as u32, h.min(u32::MAX as u64) as u32));
+        }
+        Ok((w as u32, h as u32))
+    }
+
+    /// Pixels RGB, ligne 0 de la grille en haut (comme à l’écran)
+    fn rgb(&self, scale: u32) -> Vec<u8> {
+        self.pixels(scale, |color| color.to_vec())
+    }
+
+    fn pixels(&self, scale: u32, mut pixel: impl FnMut(&[u8; 3]) -> Vec<u8>) -> Vec<u8> {
+        let scale = scale as usize;
+        let mut data = Vec::new();
+        for row in self.colors.chunks(self.width) {
+            let mut line = Vec::new();
+            for color in row {
+                let bytes = pixel(color);
+                for _ in 0..scale {
+                    line.extend_from_slice(&bytes);
+                }
+            }
+            for _ in 0..scale {
+                data.extend_from_slice(&line);
+            }
+        }
+        data
+    }
+}
+
+/// PNG d’une génération
+pub fn write_png(frame: &Frame, scale: u32, out: impl Write) -> Result<(), ExportError> {
+    let (width, height) = frame.size(scale)?;
+    let mut encoder = png::Encoder::new(out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&frame.rgb(scale))?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Animation GIF ou APNG en boucle, `delay_ms` millisecondes par génération
+pub fn write_animation(frames: &[Frame], format: ImageFormat, scale: u32, delay_ms: u32, out: impl Write) -> Result<(), ExportError> {
+    let first = frames.first().ok_or(ExportError::NoFrames)?;
+    let (width, height) = first.size(scale)?;
+    match format {
+        // les dimensions d’un GIF tiennent sur 16 bits
+        ImageFormat::Gif if width > u16::MAX as u32 || height > u16::MAX as u32 => Err(ExportError::TooLarge(width, height)),
+        ImageFormat::Gif => write_gif(frames, width, height, scale, delay_ms, out),
+        ImageFormat::Png | ImageFormat::Apng => {
+            let mut encoder = png::Encoder::new(out, width, height);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_animated(frames.len() as u32, 0)?;
+            encoder.set_frame_delay(delay_ms.min(u16::MAX as u32) as u16, 1000)?;
+            let mut writer = encoder.write_header()?;
+            for frame in frames {
+                writer.write_image_data(&frame.rgb(scale))?;
+            }
+            writer.finish()?;
+            Ok(())
+        }
+    }
+}
+
+fn write_gif(frames: &[Frame], width: u32, height: u32, scale: u32, delay_ms: u32, out: impl Write) -> Result<(), ExportError> {
+    // palette exacte si les couleurs tiennent en 256 entrées, sinon quantification image par image
+    let mut palette: HashMap<[u8; 3], u8> = HashMap::new();
+    for color in frames.iter().flat_map(|f| &f.colors) {
+        if palette.len() > 256 {
+            break;
+        }
+        let next = palette.len();
+        palette.entry(*color).or_insert(next as u8);
+    }
+    let exact = palette.len() <= 256;
+    let mut global = vec![0u8; palette.len().min(256) * 3];
+    if exact {
+        for (color, &i) in &palette {
+            global[i as usize * 3..i as usize * 3 + 3].copy_from_slice(color);
+        }
+    }
+
+    let mut encoder = gif::Encoder::new(out, width as u16, height as u16, if exact { &global } else { &[] })?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for frame in frames {
+        let mut gif_frame = if exact {
+            gif::Frame {
+                width: width as u16,
+                height: height as u16,
+                buffer: Cow::Owned(frame.pixels(scale, |color| vec![palette[color]])),
+                ..Default::default()
+            }
+        } else {
+            gif::Frame::from_rgb_speed(width as u16, height as u16, &frame.rgb(scale), 10)
+        };
+        gif_frame.delay = (delay_ms / 10).min(u16::MAX as u32) as u16; // centièmes de seconde
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+/// Écrit une image fixe (PNG) ou une animation selon le format
+pub fn save(path: &Path, format: ImageFormat, frames: &[Frame], scale: u32, delay_ms: u32) -> Result<(), ExportError> {
+    let out = BufWriter::new(File::create(path)?);
+    match format {
+        ImageFormat::Png => write_png(frames.last().ok_or(ExportError::NoFrames)?, scale, out),
+        _ => write_animation(frames, format, scale, delay_ms, out),
+    }
+}
+
+/// Nom de fichier horodaté à la milliseconde dans le dossier courant, ex. grille_1700000000123.png ;
+/// un numéro s’ajoute si le fichier existe déjà (deux exports dans la même milliseconde)
+pub fn timestamped_path(prefix: &str, format: ImageFormat) -> PathBuf {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let extension = format.extension();
+    std::iter::once(PathBuf::from(format!("{prefix}_{millis}.{extension}")))
+        .chain((2..).map(|n| PathBuf::from(format!("{prefix}_{millis}_{n}.{extension}"))))
+        .find(|path| !path.exists())
+        .unwrap_or_default()
+}
+
+/// Réglages des exports depuis l’application
+#[derive(Resource, Clone)]
+pub struct ExportSettings {
+    /// Pixels par cellule
+    pub scale: u32,
+    /// Durée d’une image de l’animation, en millisecondes
+    pub frame_delay_ms: u32,
+    /// Nombre de générations capturées
+    pub frames: usize,
+    /// GIF ou APNG
+    pub animation_format: ImageFormat,
+}
+
+impl Default for ExportSettings {
+    fn default() -> Self {
+        Self {
+            scale: 10,
+            frame_delay_ms: 100,
+            frames: 100,
+            animation_format: ImageFormat::Gif,
+        }
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum ExportEvent {
+    SaveImage,
+    /// Démarre la capture, ou l’arrête et enregistre ce qui a été capturé
+    ToggleAnimation,
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum ExportButton {
+    Image,
+    Animation,
+    /// Bascule GIF / APNG
+    Format,
+}
+
+/// Nombre de générations capturées par une animation
+#[derive(Component)]
+pub struct AnimationFramesInput;
+
+/// Générations capturées pendant l’enregistrement d’une animation
+#[derive(Resource, Default)]
+pub struct AnimationCapture {
+    pub frames: Vec<Frame>,
+    pub recording: bool,
+    last_generation: Option<u64>,
+}
+
+fn current_frame(query: &Query<&Cell>, config: &GridConfig, history: &PopulationHistory, theme: &Theme) -> Frame {
+    let generation = history.latest().map_or(0, |s| s.generation);
+    Frame::from_grid(&Grid::from_cells(config, generation, query.iter().copied()), theme)
+}
+
+fn finish_animation(capture: &mut AnimationCapture, settings: &ExportSettings, loc: &Localization, ev_toast: &mut EventWriter<Toast>) {
+    capture.recording = false;
+    capture.last_generation = None;
+    let frames = std::mem::take(&mut capture.frames);
+    let format = settings.animation_format;
+    let path = timestamped_path("animation", format);
+    ev_toast.send(match save(&path, format, &frames, settings.scale, settings.frame_delay_ms) {
+        Ok(()) => Toast::info(loc.t_with("export-animation-saved", &[("frames", frames.len().into()), ("path", path.display().to_string().into())])),
+        Err(e) => Toast::error(loc.t_with("export-animation-failed", &[("error", e.to_string().into())])),
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn export_system(
+    mut events: EventReader<ExportEvent>,
+    query: Query<&Cell>,
+    config: Res<GridConfig>,
+    history: Res<PopulationHistory>,
+    settings: Res<ExportSettings>,
+    theme: Res<Theme>,
+    loc: Res<Localization>,
+    mut capture: ResMut<AnimationCapture>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    for event in events.read() {
+        match event {
+            ExportEvent::SaveImage => {
+                let path = timestamped_path("grille", ImageFormat::Png);
+                let frame = current_frame(&query, &config, &history, &theme);
+                ev_toast.send(match save(&path, ImageFormat::Png, &[frame], settings.scale, 0) {
+                    Ok(()) => Toast::info(loc.t_with("export-image-saved", &[("path", path.display().to_string().into())])),
+                    Err(e) => Toast::error(loc.t_with("export-image-failed", &[("error", e.to_string().into())])),
+                });
+            }
+            ExportEvent::ToggleAnimation if capture.recording => finish_animation(&mut capture, &settings, &loc, &mut ev_toast),
+            ExportEvent::ToggleAnimation => {
+                capture.recording = true;
+                capture.frames.clear();
+            }
+        }
+    }
+
+    // 🎞️ Une image par nouvelle génération (la génération courante d’abord)
+    let generation = history.latest().map(|s| s.generation);
+    if !capture.recording || capture.last_generation == generation {
+        return;
+    }
+    // une nouvelle grille ou une session chargée change les dimensions : on s’arrête là
+    if capture.frames.first().is_some_and(|f| (f.width, f.height) != (config.width, config.height)) {
+        finish_animation(&mut capture, &settings, &loc, &mut ev_toast);
+        return;
+    }
+    capture.last_generation = generation;
+    let frame = current_frame(&query, &config, &history, &theme);
+    capture.frames.push(frame);
+    if capture.frames.len() >= settings.frames {
+        finish_animation(&mut capture, &settings, &loc, &mut ev_toast);
+    }
+}
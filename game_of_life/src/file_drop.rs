@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::path::{Path, PathBuf};
+use crate::{
+    camera::{grid_viewport_rect, screen_to_world, GridCamera, ViewEvent},
+    cell::Cell,
+    detection::CycleDetector,
+    edits::EditHistory,
+    grid::{Grid, GridConfig, MAX_SIZE},
+    mutation_setting::MutationSettings,
+    pattern::{Pattern, PatternError},
+    selection::Selection,
+    session::{Session, SESSION_EXTENSION},
+    stats::PopulationHistory,
+    systems::{apply_grid, replace_grid, world_to_cell},
+    toast::Toast,
+    locale::Localization
+};
+
+/// Marge autour d’un motif déposé quand la grille doit s’agrandir pour le contenir
+const DROP_MARGIN: usize = 10;
+
+/// Ouvre un motif ou une session comme s’il était déposé hors de la grille
+#[derive(Event)]
+pub struct OpenFileEvent(pub PathBuf);
+
+enum DroppedFile {
+    Pattern(Pattern),
+    Session(Session),
+}
+
+fn load_dropped(path: &Path) -> Result<DroppedFile, PatternError> {
+    let is_session = path
+        .extension()
+        .is_some_and(|e| e.to_string_lossy().eq_ignore_ascii_case(SESSION_EXTENSION));
+    if is_session {
+        Session::load(path).map(DroppedFile::Session)
+    } else {
+        Pattern::load(path).map(DroppedFile::Pattern)
+    }
+}
+
+// 📂 Glisser-déposer : un motif lâché sur la grille y est posé à cet endroit,
+// lâché ailleurs (ou ouvert au clavier) il remplace la grille ; une session remplace toujours la partie
+#[allow(clippy::too_many_arguments)]
+pub fn file_drop_system(
+    mut events: EventReader<FileDragAndDrop>,
+    mut ev_open: EventReader<OpenFileEvent>,
+    mut commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Transform, &OrthographicProjection), With<GridCamera>>,
+    cell_entities: Query<Entity, With<Cell>>,
+    mut cells: Query<&mut Cell>,
+    mut config: ResMut<GridConfig>,
+    mut settings: ResMut<MutationSettings>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut selection: ResMut<Selection>,
+    mut edits: ResMut<EditHistory>,
+    mut ev_view: EventWriter<ViewEvent>,
+    loc: Res<Localization>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    let dropped = events.read().filter_map(|event| match event {
+        FileDragAndDrop::DroppedFile { path_buf, .. } => Some((path_buf.clone(), true)),
+        _ => None,
+    });
+    let opened = ev_open.read().map(|event| (event.0.clone(), false));
+    let files: Vec<(PathBuf, bool)> = dropped.chain(opened).collect();
+
+    for (path_buf, at_cursor) in &files {
+        let file_name = path_buf.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        let message = |id: &str, error: Option<String>| {
+            let mut args = vec![("file", file_name.clone().into())];
+            args.extend(error.map(|e| ("error", e.into())));
+            loc.t_with(id, &args)
+        };
+        let too_large = |width: usize, height: usize| {
+            let args = [("file", file_name.clone().into()), ("width", width.into()), ("height", height.into()), ("max", MAX_SIZE.into())];
+            Toast::error(loc.t_with("file-too-large", &args))
+        };
+        let dropped = match load_dropped(path_buf) {
+            Ok(dropped) => dropped,
+            Err(e) => {
+                ev_toast.send(Toast::error(message("file-failed", Some(e.to_string()))));
+                continue;
+            }
+        };
+
+        // cellule sous le curseur au moment du dépôt (si la grille est visée)
+        let target = windows.get_single().ok().filter(|_| *at_cursor).zip(cameras.get_single().ok()).and_then(|(window, (transform, projection))| {
+            let cursor = window.cursor_position().filter(|c| grid_viewport_rect(window).contains(*c))?;
+            let (x, y) = world_to_cell(config.width, config.height, screen_to_world(window, transform, projection.scale, cursor));
+            (x >= 0 && y >= 0 && (x as usize) < config.width && (y as usize) < config.height).then_some((x, y))
+        });
+
+        let generation = history.latest().map_or(0, |s| s.generation);
+        let before = Grid::from_cells(&config, generation, cells.iter().copied());
+        let grid = match (dropped, target) {
+            (DroppedFile::Pattern(pattern), Some((x, y))) => {
+                // posé centré sur le point de dépôt, dans la grille actuelle
+                let mut grid = before.clone();
+                grid.stamp(&pattern, x - pattern.width as i64 / 2, y - pattern.height as i64 / 2);
+                apply_grid(&grid, &mut cells);
+                edits.record(before, &grid);
+                history.replace_latest(grid.stats());
+                detector.reset();
+                ev_toast.send(Toast::info(message("file-placed", None)));
+                continue;
+            }
+            (DroppedFile::Pattern(pattern), None) => {
+                // la grille s’agrandit si besoin, dans la limite de `MAX_SIZE`, et adopte la règle du motif
+                let width = config.width.max(pattern.width.saturating_add(2 * DROP_MARGIN));
+                let height = config.height.max(pattern.height.saturating_add(2 * DROP_MARGIN));
+                if width > MAX_SIZE || height > MAX_SIZE {
+                    ev_toast.send(too_large(width, height));
+                    continue;
+                }
+                config.width = width;
+                config.height = height;
+                if let Some(rule) = pattern.rule {
+                    config.rule = rule;
+                }
+                Grid::with_pattern(&config, &pattern)
+            }
+            (DroppedFile::Session(session), _) if session.width > MAX_SIZE || session.height > MAX_SIZE => {
+                ev_toast.send(too_large(session.width, session.height));
+                continue;
+            }
+            (DroppedFile::Session(session), _) => match session.grid() {
+                Ok(grid) => {
+                    *config = session.grid_config();
+                    *settings = session.mutations;
+                    grid
+                }
+                Err(e) => {
+                    ev_toast.send(Toast::error(message("file-failed", Some(e.to_string()))));
+                    continue;
+                }
+            },
+        };
+
+        replace_grid(&mut commands, cell_entities.iter(), &grid, &mut history);
+        edits.record(before, &grid);
+        detector.reset();
+        selection.region = None;
+        ev_view.send(ViewEvent::Reset);
+        ev_toast.send(Toast::info(message("file-loaded", None)));
+    }
+}
@@ -0,0 +1,562 @@
+use bevy::prelude::*;
+use clap::{Args, ValueEnum};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use crate::{
+    cell::{Cell, MutationType},
+    grid::{Grid, GridConfig},
+    mutation_setting::MutationSettings
+};
+
+/// Part de la grille couverte par les amas du bruit
+const NOISE_COVERAGE: f32 = 0.4;
+/// Largeur de la bande morte entre deux régions, en cellules
+const REGION_GAP: f32 = 2.0;
+/// Nombre maximal de régions (borne du réglage dans l’interface)
+pub const MAX_REGIONS: usize = 12;
+
+/// Façon de remplir une nouvelle grille
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GeneratorKind {
+    /// Toute la grille au hasard
+    #[default]
+    Uniform,
+    /// Carré aléatoire au centre d’une grille vide (comme les soupes 16×16 d’apgsearch)
+    Soup,
+    /// Soupe centrée symétrique
+    Symmetric,
+    /// Amas irréguliers tirés d’un bruit de Perlin
+    Noise,
+    /// Régions séparées, chacune d’une souche
+    Regions,
+}
+
+impl GeneratorKind {
+    pub const ALL: [GeneratorKind; 5] = [
+        GeneratorKind::Uniform,
+        GeneratorKind::Soup,
+        GeneratorKind::Symmetric,
+        GeneratorKind::Noise,
+        GeneratorKind::Regions,
+    ];
+
+    pub fn message(self) -> &'static str {
+        match self {
+            GeneratorKind::Uniform => "generator-uniform",
+            GeneratorKind::Soup => "generator-soup",
+            GeneratorKind::Symmetric => "generator-symmetric",
+            GeneratorKind::Noise => "generator-noise",
+            GeneratorKind::Regions => "generator-regions",
+        }
+    }
+}
+
+/// Classes de symétrie des soupes d’apgsearch (Catagolue) : `+` pour des miroirs horizontal
+/// et vertical, `x` pour des miroirs diagonaux ; le chiffre final compte les cellules au centre
+/// de symétrie (1 : une cellule, 2 : deux cellules côte à côte, 4 : un carré de quatre)
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum, Serialize, Deserialize)]
+pub enum Symmetry {
+    /// Aucune symétrie
+    #[value(name = "C1")]
+    #[serde(rename = "C1")]
+    C1,
+    /// Demi-tour
+    #[default]
+    #[value(name = "C2_1")]
+    #[serde(rename = "C2_1")]
+    C2_1,
+    #[value(name = "C2_2")]
+    #[serde(rename = "C2_2")]
+    C2_2,
+    #[value(name = "C2_4")]
+    #[serde(rename = "C2_4")]
+    C2_4,
+    /// Quart de tour
+    #[value(name = "C4_1")]
+    #[serde(rename = "C4_1")]
+    C4_1,
+    #[value(name = "C4_4")]
+    #[serde(rename = "C4_4")]
+    C4_4,
+    /// Un miroir vertical
+    #[value(name = "D2_+1")]
+    #[serde(rename = "D2_+1")]
+    D2Plus1,
+    #[value(name = "D2_+2")]
+    #[serde(rename = "D2_+2")]
+    D2Plus2,
+    /// Un miroir diagonal
+    #[value(name = "D2_x")]
+    #[serde(rename = "D2_x")]
+    D2Diagonal,
+    /// Miroirs vertical et horizontal
+    #[value(name = "D4_+1")]
+    #[serde(rename = "D4_+1")]
+    D4Plus1,
+    #[value(name = "D4_+2")]
+    #[serde(rename = "D4_+2")]
+    D4Plus2,
+    #[value(name = "D4_+4")]
+    #[serde(rename = "D4_+4")]
+    D4Plus4,
+    /// Deux miroirs diagonaux
+    #[value(name = "D4_x1")]
+    #[serde(rename = "D4_x1")]
+    D4Diagonal1,
+    #[value(name = "D4_x4")]
+    #[serde(rename = "D4_x4")]
+    D4Diagonal4,
+    /// Quarts de tour et miroirs
+    #[value(name = "D8_1")]
+    #[serde(rename = "D8_1")]
+    D8_1,
+    #[value(name = "D8_4")]
+    #[serde(rename = "D8_4")]
+    D8_4,
+}
+
+/// Transformation du plan autour du centre de symétrie, en coordonnées doublées
+/// (le centre peut tomber entre deux cellules)
+#[derive(Clone, Copy)]
+enum Isometry {
+    Identity,
+    QuarterTurn,
+    HalfTurn,
+    ThreeQuarterTurn,
+    /// Miroir d’axe vertical
+    MirrorX,
+    /// Miroir d’axe horizontal
+    MirrorY,
+    /// Miroir selon la diagonale
+    Diagonal,
+    /// Miroir selon l’autre diagonale
+    AntiDiagonal,
+}
+
+impl Isometry {
+    fn apply(self, x: i64, y: i64) -> (i64, i64) {
+        match self {
+            Isometry::Identity => (x, y),
+            Isometry::QuarterTurn => (-y, x),
+            Isometry::HalfTurn => (-x, -y),
+            Isometry::ThreeQuarterTurn => (y, -x),
+            Isometry::MirrorX => (-x, y),
+            Isometry::MirrorY => (x, -y),
+            Isometry::Diagonal => (y, x),
+            Isometry::AntiDiagonal => (-y, -x),
+        }
+    }
+}
+
+/// Parité imposée à un côté de la soupe
+#[derive(Clone, Copy, PartialEq)]
+enum Parity {
+    Any,
+    Odd,
+    Even,
+}
+
+impl Parity {
+    /// Plus grand côté au plus égal à `side` qui respecte la parité
+    fn fit(self, side: usize) -> usize {
+        match self {
+            Parity::Odd if side.is_multiple_of(2) => side - 1,
+            Parity::Even if !side.is_multiple_of(2) && side > 1 => side - 1,
+            _ => side,
+        }
+    }
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 16] = [
+        Symmetry::C1,
+        Symmetry::C2_1,
+        Symmetry::C2_2,
+        Symmetry::C2_4,
+        Symmetry::C4_1,
+        Symmetry::C4_4,
+        Symmetry::D2Plus1,
+        Symmetry::D2Plus2,
+        Symmetry::D2Diagonal,
+        Symmetry::D4Plus1,
+        Symmetry::D4Plus2,
+        Symmetry::D4Plus4,
+        Symmetry::D4Diagonal1,
+        Symmetry::D4Diagonal4,
+        Symmetry::D8_1,
+        Symmetry::D8_4,
+    ];
+
+    pub fn message(self) -> &'static str {
+        match self {
+            Symmetry::C1 => "symmetry-c1",
+            Symmetry::C2_1 => "symmetry-c2-1",
+            Symmetry::C2_2 => "symmetry-c2-2",
+            Symmetry::C2_4 => "symmetry-c2-4",
+            Symmetry::C4_1 => "symmetry-c4-1",
+            Symmetry::C4_4 => "symmetry-c4-4",
+            Symmetry::D2Plus1 => "symmetry-d2-plus-1",
+            Symmetry::D2Plus2 => "symmetry-d2-plus-2",
+            Symmetry::D2Diagonal => "symmetry-d2-x",
+            Symmetry::D4Plus1 => "symmetry-d4-plus-1",
+            Symmetry::D4Plus2 => "symmetry-d4-plus-2",
+            Symmetry::D4Plus4 => "symmetry-d4-plus-4",
+            Symmetry::D4Diagonal1 => "symmetry-d4-x1",
+            Symmetry::D4Diagonal4 => "symmetry-d4-x4",
+            Symmetry::D8_1 => "symmetry-d8-1",
+            Symmetry::D8_4 => "symmetry-d8-4",
+        }
+    }
+
+    /// Groupe de transformations
+    fn transforms(self) -> &'static [Isometry] {
+        use Isometry::*;
+        match self {
+            Symmetry::C1 => &[Identity],
+            Symmetry::C2_1 | Symmetry::C2_2 | Symmetry::C2_4 => &[Identity, HalfTurn],
+            Symmetry::C4_1 | Symmetry::C4_4 => &[Identity, QuarterTurn, HalfTurn, ThreeQuarterTurn],
+            Symmetry::D2Plus1 | Symmetry::D2Plus2 => &[Identity, MirrorX],
+            Symmetry::D2Diagonal => &[Identity, Diagonal],
+            Symmetry::D4Plus1 | Symmetry::D4Plus2 | Symmetry::D4Plus4 => &[Identity, MirrorX, MirrorY, HalfTurn],
+            Symmetry::D4Diagonal1 | Symmetry::D4Diagonal4 => &[Identity, Diagonal, AntiDiagonal, HalfTurn],
+            Symmetry::D8_1 | Symmetry::D8_4 => {
+                &[Identity, QuarterTurn, HalfTurn, ThreeQuarterTurn, MirrorX, MirrorY, Diagonal, AntiDiagonal]
+            }
+        }
+    }
+
+    /// Parités de la largeur et de la hauteur qui placent le centre comme le veut la classe
+    fn parities(self) -> (Parity, Parity) {
+        use Parity::*;
+        match self {
+            Symmetry::C1 | Symmetry::D2Diagonal => (Any, Any),
+            Symmetry::C2_1 | Symmetry::C4_1 | Symmetry::D4Plus1 | Symmetry::D4Diagonal1 | Symmetry::D8_1 => (Odd, Odd),
+            Symmetry::C2_2 | Symmetry::D4Plus2 => (Even, Odd),
+            Symmetry::C2_4 | Symmetry::C4_4 | Symmetry::D4Plus4 | Symmetry::D4Diagonal4 | Symmetry::D8_4 => (Even, Even),
+            Symmetry::D2Plus1 => (Odd, Any),
+            Symmetry::D2Plus2 => (Even, Any),
+        }
+    }
+
+    /// Quarts de tour et miroirs diagonaux n’ont de sens que dans un carré
+    fn needs_square(self) -> bool {
+        self.transforms().iter().any(|t| matches!(t, Isometry::QuarterTurn | Isometry::Diagonal))
+    }
+
+    /// Largeur et hauteur de la soupe de côté `side` pour cette classe
+    fn soup_size(self, side: usize) -> (usize, usize) {
+        let (px, py) = self.parities();
+        if self.needs_square() {
+            let side = px.fit(side);
+            (side, side)
+        } else {
+            (px.fit(side), py.fit(side))
+        }
+    }
+
+    /// Images de (x, y) dans un rectangle width × height, centre au milieu ; celles qui tombent
+    /// hors du rectangle ou entre deux cellules (quart de tour d’un rectangle) sont ignorées
+    pub fn images(self, x: usize, y: usize, width: usize, height: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (w, h) = (width as i64, height as i64);
+        let (dx, dy) = (2 * x as i64 - (w - 1), 2 * y as i64 - (h - 1));
+        self.transforms().iter().filter_map(move |t| {
+            let (tx, ty) = t.apply(dx, dy);
+            let (x2, y2) = (tx + w - 1, ty + h - 1);
+            let inside = x2 >= 0 && y2 >= 0 && x2 % 2 == 0 && y2 % 2 == 0 && x2 / 2 < w && y2 / 2 < h;
+            inside.then_some(((x2 / 2) as usize, (y2 / 2) as usize))
+        })
+    }
+}
+
+/// Réglage du générateur modifié par un widget du panneau
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum GeneratorControl {
+    Kind,
+    Density,
+    SoupSize,
+    Symmetry,
+    NoiseScale,
+    Regions,
+}
+
+/// Générateur choisi pour « Nouvelle Grille » (et les soupes du mode batch)
+#[derive(Resource, Args, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Generator {
+    /// Façon de remplir une nouvelle grille
+    #[arg(long = "generator", value_enum, default_value_t = GeneratorKind::Uniform)]
+    pub kind: GeneratorKind,
+    /// Proportion de cellules vivantes dans la zone remplie
+    #[arg(long, default_value_t = 0.5, value_parser = parse_density)]
+    pub density: f32,
+    /// Côté de la soupe centrée
+    #[arg(long, default_value_t = 16)]
+    pub soup_size: usize,
+    /// Classe de symétrie des soupes symétriques (C1, C2_1 … D8_4, comme sur Catagolue)
+    #[arg(long, value_enum, default_value_t = Symmetry::C2_1)]
+    pub symmetry: Symmetry,
+    /// Taille moyenne des amas du bruit, en cellules
+    #[arg(long, default_value_t = 8.0)]
+    pub noise_scale: f32,
+    /// Nombre de régions
+    #[arg(long, default_value_t = 3, value_parser = parse_regions)]
+    pub regions: usize,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            kind: GeneratorKind::Uniform,
+            density: 0.5,
+            soup_size: 16,
+            symmetry: Symmetry::C2_1,
+            noise_scale: 8.0,
+            regions: 3,
+        }
+    }
+}
+
+fn parse_density(value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(density) if (0.0..=1.0).contains(&density) => Ok(density),
+        _ => Err("densité attendue entre 0 et 1".to_string()),
+    }
+}
+
+fn parse_regions(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(regions) if (1..=MAX_REGIONS).contains(&regions) => Ok(regions),
+        _ => Err(format!("nombre de régions attendu entre 1 et {MAX_REGIONS}")),
+    }
+}
+
+impl Generator {
+    /// Densité ramenée entre 0 et 1 (valeur par défaut si elle n’est pas un nombre fini)
+    pub fn density(&self) -> f64 {
+        let density = if self.density.is_finite() { self.density } else { Self::default().density };
+        density.clamp(0.0, 1.0) as f64
+    }
+
+    /// Nouvelle grille (génération 0) ; les mutations suivent les réglages, sauf pour les régions
+    pub fn generate(&self, config: &GridConfig, settings: &MutationSettings, rng: &mut impl Rng) -> Grid {
+        match self.kind {
+            GeneratorKind::Uniform => Grid::random(config, self.density(), settings, rng),
+            GeneratorKind::Soup => {
+                let mut grid = Grid::empty(config);
+                let (x0, y0, side) = self.soup_square(&grid);
+                for y in y0..y0 + side {
+                    for x in x0..x0 + side {
+                        let i = grid.index(x, y);
+                        grid.cells[i] = Cell::new(x, y, self.density(), settings, rng);
+                    }
+                }
+                grid
+            }
+            GeneratorKind::Symmetric => {
+                let mut grid = Grid::empty(config);
+                let (width, height) = self.symmetry.soup_size(self.soup_size.max(1).min(config.width).min(config.height));
+                let (x0, y0) = ((config.width - width) / 2, (config.height - height) / 2);
+                let soup: Vec<Cell> = (0..width * height)
+                    .map(|i| Cell::new(i % width, i / width, self.density(), settings, rng))
+                    .collect();
+                // chaque cellule reprend l’état du premier point de son orbite
+                for y in 0..height {
+                    for x in 0..width {
+                        let (rx, ry) = self.symmetry.images(x, y, width, height).min_by_key(|&(x, y)| (y, x)).unwrap_or((x, y));
+                        let i = grid.index(x0 + x, y0 + y);
+                        grid.cells[i] = Cell { x: x0 + x, y: y0 + y, ..soup[ry * width + rx] };
+                    }
+                }
+                grid
+            }
+            GeneratorKind::Noise => {
+                let mut grid = Grid::empty(config);
+                let noise = Perlin::new(config.width, config.height, self.noise_scale.max(1.0), rng);
+                let values: Vec<f32> = grid.cells.iter().map(|c| noise.sample(c.x as f32, c.y as f32)).collect();
+                // seuil choisi pour que les amas couvrent toujours la même part de la grille
+                let mut sorted = values.clone();
+                sorted.sort_by(f32::total_cmp);
+                let threshold = sorted[((1.0 - NOISE_COVERAGE) * (sorted.len() - 1) as f32) as usize];
+                for (cell, value) in grid.cells.iter_mut().zip(values) {
+                    if value > threshold {
+                        *cell = Cell::new(cell.x, cell.y, self.density(), settings, rng);
+                    }
+                }
+                grid
+            }
+            GeneratorKind::Regions => {
+                let mut grid = Grid::empty(config);
+                let strains: Vec<MutationType> = MutationType::ALL
+                    .into_iter()
+                    .filter(|m| match m {
+                        MutationType::None => true,
+                        MutationType::Blue => settings.allow_blue,
+                        MutationType::Red => settings.allow_red,
+                    })
+                    .collect();
+                let seeds: Vec<Vec2> = (0..self.regions.clamp(1, MAX_REGIONS))
+                    .map(|_| Vec2::new(rng.gen_range(0.0..config.width as f32), rng.gen_range(0.0..config.height as f32)))
+                    .collect();
+                for cell in &mut grid.cells {
+                    let p = Vec2::new(cell.x as f32, cell.y as f32);
+                    let (region, nearest) = seeds
+                        .iter()
+                        .enumerate()
+                        .min_by(|(_, a), (_, b)| a.distance_squared(p).total_cmp(&b.distance_squared(p)))
+                        .map(|(i, s)| (i, *s))
+                        .unwrap_or_default();
+                    // distance à la frontière la plus proche (médiatrice entre deux germes)
+                    let border = seeds
+                        .iter()
+                        .filter(|s| **s != nearest)
+                        .map(|s| (s.distance_squared(p) - nearest.distance_squared(p)) / (2.0 * s.distance(nearest)))
+                        .fold(f32::INFINITY, f32::min);
+                    if border >= REGION_GAP / 2.0 {
+                        *cell = Cell::new(cell.x, cell.y, self.density(), settings, rng);
+                        cell.mutation = strains[region % strains.len()];
+                    }
+                }
+                grid
+            }
+        }
+    }
+
+    /// Coin haut-gauche et côté de la soupe centrée
+    fn soup_square(&self, grid: &Grid) -> (usize, usize, usize) {
+        let side = self.soup_size.max(1).min(grid.width).min(grid.height);
+        ((grid.width - side) / 2, (grid.height - side) / 2, side)
+    }
+}
+
+/// Bruit de Perlin à deux octaves : gradients aléatoires aux nœuds d’un réseau de pas `scale`
+struct Perlin {
+    gradients: Vec<Vec2>,
+    columns: usize,
+    scale: f32,
+}
+
+impl Perlin {
+    fn new(width: usize, height: usize, scale: f32, rng: &mut impl Rng) -> Self {
+        // l’octave fine a un pas deux fois plus petit
+        let columns = (2.0 * width as f32 / scale) as usize + 2;
+        let rows = (2.0 * height as f32 / scale) as usize + 2;
+        let gradients = (0..columns * rows).map(|_| Vec2::from_angle(rng.gen_range(0.0..TAU))).collect();
+        Self { gradients, columns, scale }
+    }
+
+    fn sample(&self, x: f32, y: f32) -> f32 {
+        self.octave(x / self.scale, y / self.scale) + 0.5 * self.octave(2.0 * x / self.scale, 2.0 * y / self.scale)
+    }
+
+    fn octave(&self, x: f32, y: f32) -> f32 {
+        let (cx, cy) = (x.floor() as usize, y.floor() as usize);
+        let (fx, fy) = (x.fract(), y.fract());
+        let corner = |dx: usize, dy: usize| {
+            let gradient = self.gradients[(cy + dy) * self.columns + cx + dx];
+            gradient.dot(Vec2::new(fx - dx as f32, fy - dy as f32))
+        };
+        let fade = |t: f32| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+        let (u, v) = (fade(fx), fade(fy));
+        let top = corner(0, 0) + u * (corner(1, 0) - corner(0, 0));
+        let bottom = corner(0, 1) + u * (corner(1, 1) - corner(0, 1));
+        top + v * (bottom - top)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn orbit(symmetry: Symmetry, x: usize, y: usize, width: usize, height: usize) -> BTreeSet<(usize, usize)> {
+        symmetry.images(x, y, width, height).collect()
+    }
+
+    #[test]
+    fn soup_size_respects_parity_and_squareness() {
+        for symmetry in Symmetry::ALL {
+            let (px, py) = symmetry.parities();
+            for side in 2..=9 {
+                let (width, height) = symmetry.soup_size(side);
+                assert!(width <= side && height <= side && width >= side - 1 && height >= side - 1, "{symmetry:?} {side}");
+                for (parity, length) in [(px, width), (py, height)] {
+                    match parity {
+                        Parity::Odd => assert!(length % 2 == 1, "{symmetry:?} {side}"),
+                        Parity::Even => assert!(length % 2 == 0, "{symmetry:?} {side}"),
+                        Parity::Any => assert_eq!(length, side, "{symmetry:?} {side}"),
+                    }
+                }
+                if symmetry.needs_square() {
+                    assert_eq!(width, height, "{symmetry:?} {side}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn orbits_are_complete_and_closed() {
+        for symmetry in Symmetry::ALL {
+            for side in [5, 8, 16] {
+                let (width, height) = symmetry.soup_size(side);
+                for y in 0..height {
+                    for x in 0..width {
+                        // aucune image perdue dans une soupe de la bonne taille
+                        assert_eq!(symmetry.images(x, y, width, height).count(), symmetry.transforms().len(), "{symmetry:?} ({x}, {y})");
+                        let images = orbit(symmetry, x, y, width, height);
+                        assert!(images.contains(&(x, y)));
+                        for &(ix, iy) in &images {
+                            assert_eq!(orbit(symmetry, ix, iy, width, height), images, "{symmetry:?} ({x}, {y})");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_cells_match_catagolue_centres() {
+        let side = 8;
+        for symmetry in Symmetry::ALL {
+            let (width, height) = symmetry.soup_size(side);
+            let fixed = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .filter(|&(x, y)| orbit(symmetry, x, y, width, height).len() == 1)
+                .count();
+            let expected = match symmetry {
+                Symmetry::C1 => width * height,
+                // centre sur une cellule
+                Symmetry::C2_1 | Symmetry::C4_1 | Symmetry::D4Plus1 | Symmetry::D4Diagonal1 | Symmetry::D8_1 => 1,
+                // axe vertical sur une colonne de cellules
+                Symmetry::D2Plus1 => height,
+                Symmetry::D2Diagonal => width,
+                // centre sur un bord ou un coin : aucune cellule fixe
+                _ => 0,
+            };
+            assert_eq!(fixed, expected, "{symmetry:?}");
+        }
+    }
+
+    #[test]
+    fn images_skip_points_between_cells() {
+        // quart de tour d’un rectangle : des images tombent hors de la grille des cellules
+        let count = Symmetry::C4_1.images(0, 0, 5, 3).count();
+        assert!(count < 4);
+        assert_eq!(Symmetry::C1.images(2, 1, 5, 3).collect::<Vec<_>>(), vec![(2, 1)]);
+        assert_eq!(orbit(Symmetry::C2_1, 0, 0, 5, 3), BTreeSet::from([(0, 0), (4, 2)]));
+    }
+
+    #[test]
+    fn density_and_regions_are_bounded() {
+        assert!(parse_density("NaN").is_err());
+        assert!(parse_density("inf").is_err());
+        assert!(parse_density("1.5").is_err());
+        assert_eq!(parse_density("0.3"), Ok(0.3));
+        assert!(parse_regions("0").is_err());
+        assert!(parse_regions("13").is_err());
+        assert_eq!(parse_regions("12"), Ok(12));
+        let generator = Generator { density: f32::NAN, ..Default::default() };
+        assert_eq!(generator.density(), 0.5);
+    }
+}
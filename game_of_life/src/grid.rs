@@ -0,0 +1,244 @@
+use bevy::prelude::Resource;
+use clap::ValueEnum;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::{
+    cell::Cell,
+    mutation_setting::MutationSettings,
+    pattern::Pattern,
+    rule::Rule,
+    stats::GenerationStats
+};
+
+/// Comportement aux bords de la grille
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Boundary {
+    /// Les cellules hors de la grille sont mortes
+    #[default]
+    Dead,
+    /// Les bords opposés se rejoignent (tore)
+    Torus,
+}
+
+/// Côté maximal d’une grille ouverte depuis un fichier (une entité par cellule)
+pub const MAX_SIZE: usize = 2000;
+
+/// Dimensions, bords et règle de base de la grille
+#[derive(Resource, Clone)]
+pub struct GridConfig {
+    pub width: usize,
+    pub height: usize,
+    pub boundary: Boundary,
+    pub rule: Rule,
+}
+
+impl Default for GridConfig {
+    fn default() -> Self {
+        Self {
+            width: 20,
+            height: 20,
+            boundary: Boundary::Dead,
+            rule: Rule::CONWAY,
+        }
+    }
+}
+
+/// Grille indépendante de Bevy : c’est elle qui applique les règles,
+/// aussi bien dans l’application que dans le mode batch
+#[derive(Clone)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub boundary: Boundary,
+    pub rule: Rule,
+    pub generation: u64,
+    pub cells: Vec<Cell>, // rangée par rangée : index = y * width + x
+}
+
+impl Grid {
+    /// Grille vide
+    pub fn empty(config: &GridConfig) -> Self {
+        let width = config.width;
+        Self {
+            width,
+            height: config.height,
+            boundary: config.boundary,
+            rule: config.rule,
+            generation: 0,
+            cells: (0..config.height)
+                .flat_map(|y| (0..width).map(move |x| Cell::dead(x, y)))
+                .collect(),
+        }
+    }
+
+    /// Grille aléatoire (proportion `density` de cellules vivantes, mutations selon les réglages)
+    pub fn random(config: &GridConfig, density: f64, settings: &MutationSettings, rng: &mut impl Rng) -> Self {
+        let mut grid = Self::empty(config);
+        for cell in &mut grid.cells {
+            *cell = Cell::new(cell.x, cell.y, density, settings, rng);
+        }
+        grid
+    }
+
+    /// Grille vide avec le motif au centre (les cellules qui dépassent sont ignorées)
+    pub fn with_pattern(config: &GridConfig, pattern: &Pattern) -> Self {
+        let mut grid = Self::empty(config);
+        let offset_x = (config.width as i64 - pattern.width as i64) / 2;
+        let offset_y = (config.height as i64 - pattern.height as i64) / 2;
+        grid.stamp(pattern, offset_x, offset_y);
+        grid
+    }
+
+    /// Reconstruit une grille à partir de cellules dans un ordre quelconque (les absentes sont mortes)
+    pub fn from_cells(config: &GridConfig, generation: u64, cells: impl IntoIterator<Item = Cell>) -> Self {
+        let mut grid = Self::empty(config);
+        grid.generation = generation;
+        for cell in cells {
+            if cell.x < grid.width && cell.y < grid.height {
+                let i = grid.index(cell.x, cell.y);
+                grid.cells[i] = cell;
+            }
+        }
+        grid
+    }
+
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
+        (x < self.width && y < self.height).then(|| &self.cells[self.index(x, y)])
+    }
+
+    /// Copie du rectangle (x, y, largeur, hauteur) sous forme de motif, mutations comprises
+    pub fn extract(&self, x: usize, y: usize, width: usize, height: usize) -> Pattern {
+        let cells = (y..y + height)
+            .flat_map(|cy| (x..x + width).map(move |cx| (cx, cy)))
+            .filter_map(|(cx, cy)| self.get(cx, cy))
+            .filter(|c| c.alive)
+            .map(|c| (c.x - x, c.y - y, c.mutation))
+            .collect();
+        Pattern { width, height, cells, rule: Some(self.rule), name: None }
+    }
+
+    /// Colle le motif avec son coin haut-gauche en (x, y) : toute sa boîte est remplacée,
+    /// les cellules hors de la grille sont ignorées
+    pub fn stamp(&mut self, pattern: &Pattern, x: i64, y: i64) {
+        let (w, h) = (self.width as i64, self.height as i64);
+        // boîte du motif ramenée à la grille
+        for gy in y.max(0)..y.saturating_add(pattern.height as i64).min(h) {
+            for gx in x.max(0)..x.saturating_add(pattern.width as i64).min(w) {
+                let i = self.index(gx as usize, gy as usize);
+                self.cells[i] = Cell::dead(gx as usize, gy as usize);
+            }
+        }
+        self.overlay(pattern, x, y);
+    }
+
+    /// Comme `stamp`, mais seules les cellules vivantes du motif sont posées : le reste de sa boîte est gardé
+    pub fn overlay(&mut self, pattern: &Pattern, x: i64, y: i64) {
+        let (w, h) = (self.width as i64, self.height as i64);
+        let inside = |gx: i64, gy: i64| gx >= 0 && gy >= 0 && gx < w && gy < h;
+        for &(px, py, mutation) in &pattern.cells {
+            let (gx, gy) = (x + px as i64, y + py as i64);
+            if inside(gx, gy) {
+                let i = self.index(gx as usize, gy as usize);
+                // cellule posée : elle naît, sans l’âge ni la traînée de la précédente
+                self.cells[i] = Cell { alive: true, mutation, age: 0, dead_for: 0, ..self.cells[i] };
+            }
+        }
+    }
+
+    /// Voisines vivantes (selon le comportement aux bords)
+    pub fn alive_neighbors(&self, x: usize, y: usize) -> usize {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let mut alive_neighbors = 0;
+        for dy in -1i32..=1 {
+            for dx in -1i32..=1 {
+                if dx == 0 && dy == 0 { continue; }
+                let mut nx = x as i32 + dx;
+                let mut ny = y as i32 + dy;
+                if self.boundary == Boundary::Torus {
+                    nx = nx.rem_euclid(w);
+                    ny = ny.rem_euclid(h);
+                }
+                if nx >= 0 && ny >= 0
+                    && self.get(nx as usize, ny as usize).is_some_and(|c| c.alive)
+                {
+                    alive_neighbors += 1;
+                }
+            }
+        }
+        alive_neighbors
+    }
+
+    /// Statistiques de la génération courante (sans naissances ni morts)
+    pub fn stats(&self) -> GenerationStats {
+        let mut stats = GenerationStats {
+            generation: self.generation,
+            ..Default::default()
+        };
+        for cell in &self.cells {
+            stats.count(cell);
+        }
+        stats
+    }
+
+    /// Positions des cellules vivantes
+    pub fn alive_positions(&self) -> Vec<(i32, i32)> {
+        self.cells
+            .iter()
+            .filter(|c| c.alive)
+            .map(|c| (c.x as i32, c.y as i32))
+            .collect()
+    }
+
+    /// Avance d’une génération et renvoie ses statistiques
+    pub fn step(&mut self, settings: &MutationSettings, rng: &mut impl Rng) -> GenerationStats {
+        // 1️⃣ Calculer le prochain état de chaque cellule à partir de l’état courant
+        let next_states: Vec<bool> = self
+            .cells
+            .iter()
+            .map(|cell| cell.change_state(self.alive_neighbors(cell.x, cell.y), &self.rule))
+            .collect();
+
+        // 2️⃣ Appliquer les changements
+        self.generation += 1;
+        let mut stats = GenerationStats {
+            generation: self.generation,
+            ..Default::default()
+        };
+        for (cell, next_state) in self.cells.iter_mut().zip(next_states) {
+            // mutation seulement si la cellule "renaît"
+            if !cell.alive && next_state {
+                cell.random_mutation(settings, rng);
+                stats.births += 1;
+            } else if cell.alive && !next_state {
+                stats.deaths += 1;
+            }
+            cell.advance(next_state);
+            stats.count(cell);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern::Pattern;
+
+    #[test]
+    fn overlay_resets_age_and_trail() {
+        let config = GridConfig { width: 5, height: 5, ..Default::default() };
+        let mut grid = Grid::empty(&config);
+        let i = grid.index(1, 1);
+        grid.cells[i].age = 7;
+        grid.cells[i].dead_for = 3;
+        grid.overlay(&Pattern::parse_rle("o!").unwrap(), 1, 1);
+        let cell = grid.cells[i];
+        assert!(cell.alive);
+        assert_eq!((cell.age, cell.dead_for), (0, 0));
+    }
+}
@@ -0,0 +1,282 @@
+use bevy::prelude::*;
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat},
+    texture::ImageSampler
+};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::{
+    cell::{Cell, MutationType},
+    export::{timestamped_path, write_png, ExportError, ExportSettings, Frame, ImageFormat},
+    grid::{Grid, GridConfig},
+    stats::PopulationHistory,
+    systems::CELL_SIZE,
+    theme::Theme,
+    toast::Toast,
+    locale::Localization
+};
+
+const CSV_HEADER: &str = "x,y,changes,alive,normal,blue,red";
+
+/// Opacité de la carte posée par-dessus les couleurs habituelles
+const OVERLAY_ALPHA: f32 = 0.75;
+
+/// Ce que montre la carte d’activité
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HeatmapMode {
+    #[default]
+    Off,
+    /// Nombre de changements d’état
+    Changes,
+    /// Nombre de générations passées en vie
+    Presence,
+    /// Présence par souche, couleurs des souches additionnées
+    Strains,
+}
+
+#[derive(Resource, Default)]
+pub struct HeatmapSettings {
+    pub mode: HeatmapMode,
+    /// Remplace les couleurs des cellules au lieu de s’y superposer
+    pub opaque: bool,
+}
+
+/// Compteurs accumulés par cellule depuis le début de la partie (ou la dernière remise à zéro)
+#[derive(Resource, Default)]
+pub struct ActivityHeatmap {
+    pub width: usize,
+    pub height: usize,
+    /// Générations observées
+    pub samples: u32,
+    pub changes: Vec<u32>,
+    /// Générations en vie, par souche (dans l’ordre de `MutationType::ALL`)
+    pub presence: Vec<[u32; 3]>,
+    last_alive: Vec<bool>,
+    last_generation: Option<u64>,
+}
+
+impl ActivityHeatmap {
+    pub fn clear(&mut self) {
+        *self = ActivityHeatmap::default();
+    }
+
+    /// Ajoute une génération ; les changements ne comptent qu’entre générations consécutives
+    pub fn observe(&mut self, grid: &Grid) {
+        let size = grid.width * grid.height;
+        if (self.width, self.height) != (grid.width, grid.height) {
+            *self = ActivityHeatmap {
+                width: grid.width,
+                height: grid.height,
+                changes: vec![0; size],
+                presence: vec![[0; 3]; size],
+                ..default()
+            };
+        }
+        let consecutive = self.last_generation.is_some_and(|g| g + 1 == grid.generation);
+
+        self.last_alive.resize(size, false);
+        for (i, cell) in grid.cells.iter().enumerate() {
+            if consecutive && cell.alive != self.last_alive[i] {
+                self.changes[i] += 1;
+            }
+            if cell.alive {
+                self.presence[i][strain_index(cell.mutation)] += 1;
+            }
+            self.last_alive[i] = cell.alive;
+        }
+        self.samples += 1;
+        self.last_generation = Some(grid.generation);
+    }
+
+    /// Couleur de chaque cellule (noir pour une cellule sans activité)
+    pub fn colors(&self, mode: HeatmapMode, theme: &Theme) -> Vec<[u8; 3]> {
+        let alive = |p: &[u32; 3]| p.iter().sum::<u32>();
+        let max = match mode {
+            HeatmapMode::Changes => self.changes.iter().copied().max(),
+            _ => self.presence.iter().map(alive).max(),
+        }
+        .unwrap_or(0)
+        .max(1) as f32;
+
+        (0..self.changes.len())
+            .map(|i| match mode {
+                HeatmapMode::Off => [0, 0, 0],
+                HeatmapMode::Changes => heat(self.changes[i] as f32 / max),
+                HeatmapMode::Presence => heat(alive(&self.presence[i]) as f32 / max),
+                HeatmapMode::Strains => {
+                    // les zones disputées mêlent les couleurs des souches (magenta pour bleu + rouge)
+                    let mixed = MutationType::ALL.iter().zip(self.presence[i]).fold(Vec3::ZERO, |sum, (mutation, n)| {
+                        sum + theme.strain(*mutation).to_srgba().to_vec3() * (n as f32 / max).sqrt()
+                    });
+                    let [r, g, b, _] = Srgba::from_vec3(mixed.min(Vec3::ONE)).to_u8_array();
+                    [r, g, b]
+                }
+            })
+            .collect()
+    }
+
+    pub fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{CSV_HEADER}")?;
+        for (i, (changes, [normal, blue, red])) in self.changes.iter().zip(&self.presence).enumerate() {
+            let (x, y) = (i % self.width, i / self.width);
+            writeln!(out, "{x},{y},{changes},{},{normal},{blue},{red}", normal + blue + red)?;
+        }
+        Ok(())
+    }
+}
+
+fn strain_index(mutation: MutationType) -> usize {
+    MutationType::ALL.iter().position(|m| *m == mutation).unwrap_or(0)
+}
+
+/// Dégradé noir → violet → orange → jaune pâle, `t` entre 0 et 1
+fn heat(t: f32) -> [u8; 3] {
+    let stops = [
+        Srgba::rgb(0.0, 0.0, 0.0),
+        Srgba::rgb(0.45, 0.1, 0.55),
+        Srgba::rgb(0.95, 0.45, 0.1),
+        Srgba::rgb(1.0, 1.0, 0.7),
+    ];
+    let t = t.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+    let i = (t as usize).min(stops.len() - 2);
+    let [r, g, b, _] = stops[i].mix(&stops[i + 1], t - i as f32).to_u8_array();
+    [r, g, b]
+}
+
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum HeatmapEvent {
+    Clear,
+    /// Image PNG et CSV des compteurs
+    Export,
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum HeatmapButton {
+    Mode(HeatmapMode),
+    Opaque,
+    Clear,
+    Export,
+}
+
+/// Sprite de la carte : une texture d’un pixel par cellule, au-dessus de la grille
+#[derive(Component)]
+pub struct HeatmapOverlay;
+
+// 🌡️ Une observation par nouvelle génération
+pub fn accumulate_heatmap(
+    query: Query<&Cell>,
+    config: Res<GridConfig>,
+    history: Res<PopulationHistory>,
+    mut heatmap: ResMut<ActivityHeatmap>,
+) {
+    let Some(generation) = history.latest().map(|s| s.generation) else {
+        return;
+    };
+    if heatmap.last_generation == Some(generation) && (heatmap.width, heatmap.height) == (config.width, config.height) {
+        return;
+    }
+    heatmap.observe(&Grid::from_cells(&config, generation, query.iter().copied()));
+}
+
+fn save_heatmap(heatmap: &ActivityHeatmap, mode: HeatmapMode, theme: &Theme, scale: u32, png_path: &Path, csv_path: &Path) -> Result<(), ExportError> {
+    let frame = Frame { width: heatmap.width, height: heatmap.height, colors: heatmap.colors(mode, theme) };
+    write_png(&frame, scale, BufWriter::new(File::create(png_path)?))?;
+    let mut csv = BufWriter::new(File::create(csv_path)?);
+    heatmap.write_csv(&mut csv)?;
+    csv.flush()?;
+    Ok(())
+}
+
+pub fn heatmap_event_system(
+    mut events: EventReader<HeatmapEvent>,
+    mut heatmap: ResMut<ActivityHeatmap>,
+    settings: Res<HeatmapSettings>,
+    export: Res<ExportSettings>,
+    theme: Res<Theme>,
+    loc: Res<Localization>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    for event in events.read() {
+        match event {
+            HeatmapEvent::Clear => heatmap.clear(),
+            HeatmapEvent::Export => {
+                // sans mode choisi, on exporte l’activité
+                let mode = match settings.mode {
+                    HeatmapMode::Off => HeatmapMode::Changes,
+                    mode => mode,
+                };
+                let png_path = timestamped_path("activite", ImageFormat::Png);
+                let csv_path = png_path.with_extension("csv");
+                ev_toast.send(match save_heatmap(&heatmap, mode, &theme, export.scale, &png_path, &csv_path) {
+                    Ok(()) => Toast::info(loc.t_with("heatmap-saved", &[
+                        ("png", png_path.display().to_string().into()),
+                        ("csv", csv_path.display().to_string().into()),
+                    ])),
+                    Err(e) => Toast::error(loc.t_with("heatmap-failed", &[("error", e.to_string().into())])),
+                });
+            }
+        }
+    }
+}
+
+// Texture de la carte reconstruite à chaque observation ou changement de réglage
+pub fn update_heatmap_overlay(
+    mut commands: Commands,
+    heatmap: Res<ActivityHeatmap>,
+    settings: Res<HeatmapSettings>,
+    theme: Res<Theme>,
+    mut images: ResMut<Assets<Image>>,
+    mut overlays: Query<(&mut Sprite, &mut Visibility, &Handle<Image>), With<HeatmapOverlay>>,
+) {
+    if !heatmap.is_changed() && !settings.is_changed() && !theme.is_changed() {
+        return;
+    }
+    let visible = settings.mode != HeatmapMode::Off && heatmap.width > 0;
+    if let Ok((_, mut visibility, _)) = overlays.get_single_mut() {
+        *visibility = if visible { Visibility::Visible } else { Visibility::Hidden };
+    }
+    if !visible {
+        return;
+    }
+
+    let alpha = |color: &[u8; 3]| match (settings.opaque, color) {
+        (true, _) => 255,
+        (false, [0, 0, 0]) => 0,
+        (false, _) => (OVERLAY_ALPHA * 255.0) as u8,
+    };
+    let data = heatmap
+        .colors(settings.mode, &theme)
+        .iter()
+        .flat_map(|c| [c[0], c[1], c[2], alpha(c)])
+        .collect();
+    let mut image = Image::new(
+        Extent3d { width: heatmap.width as u32, height: heatmap.height as u32, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.sampler = ImageSampler::nearest(); // cellules nettes
+    let size = Vec2::new(heatmap.width as f32, heatmap.height as f32) * CELL_SIZE;
+
+    match overlays.get_single_mut() {
+        Ok((mut sprite, _, handle)) => {
+            images.insert(handle, image);
+            sprite.custom_size = Some(size);
+        }
+        Err(_) => {
+            // la grille est centrée sur l’origine ; z entre les cellules et la sélection
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite { custom_size: Some(size), ..default() },
+                    texture: images.add(image),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.5),
+                    ..default()
+                },
+                HeatmapOverlay,
+            ));
+        }
+    }
+}
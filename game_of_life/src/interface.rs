@@ -1,55 +1,182 @@
 use crate::{
-    cell,
-    systems::{ StepTimer,MAX_SPEED, MIN_SPEED, set_grid},
+    cell::{self, ColorMode, ColorModeButton, MutationType},
+    draw::{DrawControl, DrawSettings},
+    edits::EditHistory,
+    generator::{Generator, GeneratorControl, GeneratorKind, Symmetry},
+    stats::{PopulationHistory, HISTORY_LEN},
+    grid::{Grid, GridConfig},
+    systems::{ StepTimer,SimRng, replace_grid},
     visual_elements::panel::{spawn_left_panel,spawn_right_panel},
-    mutation_setting::{MutationCheckbox,MutationSettings}
-    
+    mutation_setting::{MutationCheckbox,MutationSettings},
+    recorder::{StatsRecorder, default_record_path},
+    detection::{CycleDetector, StabilizationCheckbox, StabilizationKind, StabilizationSettings},
+    census::{CensusRequestEvent, ObjectCensus},
+    camera::{GridCamera, ViewButton, ViewEvent},
+    selection::{Selection, SelectionAction, SelectionButton},
+    library::PatternLibrary,
+    session::SaveSessionEvent,
+    heatmap::{HeatmapButton, HeatmapEvent, HeatmapMode, HeatmapSettings},
+    export::{AnimationCapture, AnimationFramesInput, ExportButton, ExportEvent, ExportSettings, ImageFormat},
+    visual_elements::library::spawn_library_entries,
+    theme::Theme,
+    shortcuts::KeyBindings,
+    visual_elements::help::spawn_help_entries,
+    locale::{Language, LanguageSelector, Localization, Localized},
+    widgets::{
+        button::{set_button_label, Highlight},
+        dropdown::{Dropdown, DropdownChanged},
+        number::{NumberChanged, NumberInput},
+        slider::{Slider, SliderChanged},
+        text_field::TextField,
+        toggle::{Toggle, ToggleChanged},
+        FONT
+    }
 };
 use bevy::core_pipeline::core_2d::Camera2dBundle;
-use bevy::ecs::system::ParamSet;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
 
 #[derive(Component)]
 pub struct SetWindowButton;
+
+#[derive(Component)]
+pub struct PauseButton;
+
 #[derive(Event)]
 pub struct ResetGridEvent;
 
+/// Curseur de la durée d’une étape
 #[derive(Component)]
 pub struct SpeedSlider;
 
 #[derive(Component)]
-pub struct SliderHandle;
+pub struct RecordButton;
+
+#[derive(Component)]
+pub struct StabilizationText;
+
+#[derive(Component)]
+pub struct CensusButton;
+
+#[derive(Component)]
+pub struct CensusText;
+
+pub const GRAPH_WIDTH: f32 = 260.0;
+pub const GRAPH_HEIGHT: f32 = 120.0;
+
+/// Courbe affichée par le graphe de population
+#[derive(Clone, Copy, PartialEq)]
+pub enum GraphSeries {
+    Total,
+    Strain(MutationType),
+}
+
+impl GraphSeries {
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            GraphSeries::Total => theme.ui.text,
+            GraphSeries::Strain(mutation) => theme.strain(mutation),
+        }
+    }
+}
+
+/// Point d’une courbe dans une colonne (une colonne = une génération)
+#[derive(Component)]
+pub struct GraphPoint {
+    pub column: usize,
+    pub series: GraphSeries,
+}
+
+/// Segment de la vue empilée (part d’une souche dans une colonne)
+#[derive(Component)]
+pub struct GraphSegment {
+    pub column: usize,
+    pub mutation: MutationType,
+}
+
+#[derive(Component)]
+pub struct GraphLegend;
+
+#[derive(Component)]
+pub struct GraphModeButton;
+
+#[derive(Component)]
+pub struct SaveSessionButton;
+
+#[derive(Component)]
+pub struct LibraryButton;
+
+#[derive(Component)]
+pub struct LibraryPanel;
 
+/// Zone visible de la liste des motifs (défilement à la molette)
 #[derive(Component)]
-pub struct SpeedText;
+pub struct LibraryList;
 
-#[derive(Resource, Default)]
-pub struct LastCursorPos(pub Option<Vec2>);
+#[derive(Component)]
+pub struct LibraryContent;
 
-pub const BAR_WIDTH: f32 = 100.0;
-pub const HANDLE_WIDTH: f32 = 2.0;
+/// Champ de recherche de la bibliothèque
+#[derive(Component)]
+pub struct LibraryFilter;
 
+/// Aide des raccourcis clavier (touche ?)
+#[derive(Component)]
+pub struct HelpOverlay;
+
+/// Colonnes « touches — action » de l’aide
+#[derive(Component)]
+pub struct HelpContent;
+
+/// Index du motif dans `PatternLibrary::entries`
+#[derive(Component)]
+pub struct LibraryEntryButton(pub usize);
+
+/// Mode d’affichage du graphe de population
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+pub enum GraphMode {
+    #[default]
+    Lines,
+    Stacked,
+}
 
-pub fn set_window(mut commands: Commands, asset_server: Res<AssetServer>) {
+
+pub fn set_window(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    timer: Res<StepTimer>,
+    settings: Res<MutationSettings>,
+    export: Res<ExportSettings>,
+    generator: Res<Generator>,
+    theme: Res<Theme>,
+) {
     spawn_cameras(&mut commands);
-    spawn_ui_root(&mut commands, &asset_server);
+    spawn_ui_root(&mut commands, &asset_server, &theme, &timer, &settings, &export, &generator);
 }
 
 fn spawn_cameras(commands: &mut Commands) {
-    // 🎮 Caméra pour la grille (z = 0)
-    commands.spawn(Camera2dBundle {
-        camera: Camera { order: 0, ..default() },
-        ..default()
-    });
+    // 🎮 Caméra pour la grille (z = 0), limitée à la zone à droite du panneau
+    commands.spawn((
+        Camera2dBundle {
+            camera: Camera { order: 0, ..default() },
+            ..default()
+        },
+        GridCamera,
+    ));
 
-    // 🖱️ Caméra pour l’interface (z = 100)
-    commands.spawn(Camera2dBundle {
-        camera: Camera { order: 1, ..default() },
-        ..default()
-    });
+    // 🖱️ Caméra pour l’interface (z = 100) : n’efface pas la grille et ne dessine pas les sprites
+    commands.spawn((
+        Camera2dBundle {
+            camera: Camera { order: 1, clear_color: ClearColorConfig::None, ..default() },
+            ..default()
+        },
+        IsDefaultUiCamera,
+        RenderLayers::layer(1),
+    ));
 }
 
-fn spawn_ui_root(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+fn spawn_ui_root(commands: &mut Commands, asset_server: &Res<AssetServer>, theme: &Theme, timer: &StepTimer, settings: &MutationSettings, export: &ExportSettings, generator: &Generator) {
     commands
         .spawn(NodeBundle {
             style: Style {
@@ -61,8 +188,8 @@ fn spawn_ui_root(commands: &mut Commands, asset_server: &Res<AssetServer>) {
             ..default()
         })
         .with_children(|parent| {
-            spawn_left_panel(parent, asset_server);
-            spawn_right_panel(parent);
+            spawn_left_panel(parent, asset_server, theme, timer, settings, export, generator);
+            spawn_right_panel(parent, asset_server, theme);
         });
 }
 
@@ -71,103 +198,180 @@ fn spawn_ui_root(commands: &mut Commands, asset_server: &Res<AssetServer>) {
 
 
 pub fn button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<SetWindowButton>),
-    >,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<SetWindowButton>)>,
     mut ev_reset: EventWriter<ResetGridEvent>, // 👈 on peut envoyer un event
 ) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = Color::srgb(0.4, 0.8, 0.4).into();
-                ev_reset.send(ResetGridEvent); // 👈 déclenche le reset
-            }
-            Interaction::Hovered => {
-                *color = Color::srgb(0.35, 0.7, 0.35).into();
-            }
-            Interaction::None => {
-                *color = Color::srgb(0.3, 0.6, 0.3).into();
-            }
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ev_reset.send(ResetGridEvent); // 👈 déclenche le reset
+        }
+    }
+}
+
+pub fn pause_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<PauseButton>)>,
+    buttons: Query<&Children, With<PauseButton>>,
+    mut labels: Query<&mut Localized>,
+    mut timer: ResMut<StepTimer>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            timer.paused = !timer.paused;
         }
     }
+
+    // ⏯️ Le libellé suit l’état (pause automatique, --paused…)
+    if timer.is_changed()
+        && let Ok(children) = buttons.get_single()
+    {
+        set_button_label(children, &mut labels, if timer.paused { "play" } else { "pause" });
+    }
 }
 
 // 👇 Ce système écoute l’événement et appelle ton vrai système set_grid
+#[allow(clippy::too_many_arguments)]
 pub fn reset_grid_system(
     mut ev_reset: EventReader<ResetGridEvent>,
     mut commands: Commands,
     query: Query<Entity, With<cell::Cell>>,
-    settings: Res<MutationSettings>
+    cells: Query<&cell::Cell>,
+    settings: Res<MutationSettings>,
+    generator: Res<Generator>,
+    config: Res<GridConfig>,
+    mut rng: ResMut<SimRng>,
+    mut history: ResMut<PopulationHistory>,
+    mut edits: ResMut<EditHistory>,
+    mut timer: ResMut<StepTimer>,
 ) {
     for _ in ev_reset.read() {
-        // Supprimer toutes les anciennes cellules
-        for e in query.iter() {
-            commands.entity(e).despawn();
+        // Une nouvelle grille relance la simulation
+        timer.paused = false;
+
+        // Remplacer toutes les anciennes cellules (et repartir d’un historique vide) ; Ctrl+Z rend l’ancienne
+        let generation = history.latest().map_or(0, |s| s.generation);
+        let before = Grid::from_cells(&config, generation, cells.iter().copied());
+        let grid = generator.generate(&config, &settings, &mut rng.0);
+        replace_grid(&mut commands, query.iter(), &grid, &mut history);
+        edits.record(before, &grid);
+    }
+}
+
+// ✏️ Mode dessin et symétrie des traits ; la case suit aussi le raccourci
+pub fn draw_control_system(
+    mut toggle_events: EventReader<ToggleChanged>,
+    mut dropdown_events: EventReader<DropdownChanged>,
+    mut toggles: Query<(&DrawControl, &mut Toggle)>,
+    mut dropdowns: Query<(&DrawControl, &mut Dropdown)>,
+    mut draw: ResMut<DrawSettings>,
+) {
+    for event in toggle_events.read() {
+        if let Ok((DrawControl::Enabled, _)) = toggles.get(event.toggle) {
+            draw.enabled = event.checked;
+        }
+    }
+    for event in dropdown_events.read() {
+        if let Ok((DrawControl::Symmetry, _)) = dropdowns.get(event.dropdown) {
+            draw.symmetry = Symmetry::ALL[event.selected];
         }
+    }
 
-        // Recréer une nouvelle grille
-        set_grid(&mut commands,&settings);
+    if !draw.is_changed() {
+        return;
+    }
+    for (_, mut toggle) in &mut toggles {
+        if toggle.checked != draw.enabled {
+            toggle.checked = draw.enabled;
+        }
+    }
+    let selected = Symmetry::ALL.iter().position(|s| *s == draw.symmetry).unwrap_or_default();
+    for (_, mut dropdown) in &mut dropdowns {
+        if dropdown.selected != selected {
+            dropdown.selected = selected;
+        }
     }
 }
 
-pub fn update_slider(
-    mut cursor: EventReader<CursorMoved>,
-    buttons: Res<ButtonInput<MouseButton>>,
-    mut param_set: ParamSet<(
-        Query<(&GlobalTransform, &Style, &Interaction), With<SpeedSlider>>,
-        Query<(&mut Style, &mut BackgroundColor), With<SliderHandle>>,
-    )>,
-    mut text_query: Query<&mut Text, With<SpeedText>>,
-    mut timer_res: ResMut<StepTimer>,
-    mut last_cursor: ResMut<LastCursorPos>,
+// 🎲 Réglages du générateur ; les widgets suivent les changements venus d’ailleurs (réglages par défaut…)
+#[allow(clippy::type_complexity)]
+pub fn generator_control_system(
+    mut dropdown_events: EventReader<DropdownChanged>,
+    mut slider_events: EventReader<SliderChanged>,
+    mut number_events: EventReader<NumberChanged>,
+    mut dropdowns: Query<(&GeneratorControl, &mut Dropdown)>,
+    mut sliders: Query<(&GeneratorControl, &mut Slider)>,
+    mut inputs: Query<(&GeneratorControl, &mut NumberInput)>,
+    mut generator: ResMut<Generator>,
 ) {
-    // 🔹 1️⃣ Mémoriser la dernière position connue
-    for event in cursor.read() {
-        last_cursor.0 = Some(event.position);
+    for event in dropdown_events.read() {
+        match dropdowns.get(event.dropdown).map(|(control, _)| *control) {
+            Ok(GeneratorControl::Kind) => generator.kind = GeneratorKind::ALL[event.selected],
+            Ok(GeneratorControl::Symmetry) => generator.symmetry = Symmetry::ALL[event.selected],
+            _ => {}
+        }
+    }
+    for event in slider_events.read() {
+        if let Ok((GeneratorControl::Density, _)) = sliders.get(event.slider) {
+            generator.density = event.value;
+        }
+    }
+    for event in number_events.read() {
+        match inputs.get(event.input).map(|(control, _)| *control) {
+            Ok(GeneratorControl::SoupSize) => generator.soup_size = event.value as usize,
+            Ok(GeneratorControl::NoiseScale) => generator.noise_scale = event.value,
+            Ok(GeneratorControl::Regions) => generator.regions = event.value as usize,
+            _ => {}
+        }
     }
 
-    // 🔹 2️⃣ Lire le slider
-    let (slider_x, slider_width, interaction) = {
-        let slider_query = param_set.p0();
-        if let Ok((transform, style, interaction)) = slider_query.get_single() {
-            let width = if let Val::Px(w) = style.width { w } else { BAR_WIDTH };
-            (transform.translation().x, width, *interaction)
-        } else {
-            return;
+    if !generator.is_changed() {
+        return;
+    }
+    for (control, mut dropdown) in &mut dropdowns {
+        let selected = match control {
+            GeneratorControl::Kind => GeneratorKind::ALL.iter().position(|k| *k == generator.kind),
+            GeneratorControl::Symmetry => Symmetry::ALL.iter().position(|s| *s == generator.symmetry),
+            _ => None,
+        };
+        if let Some(selected) = selected
+            && dropdown.selected != selected
+        {
+            dropdown.selected = selected;
         }
-    };
+    }
+    for (_, mut slider) in &mut sliders {
+        if slider.value != generator.density {
+            slider.set_value(generator.density);
+        }
+    }
+    for (control, mut input) in &mut inputs {
+        let value = match control {
+            GeneratorControl::SoupSize => generator.soup_size as f32,
+            GeneratorControl::NoiseScale => generator.noise_scale,
+            GeneratorControl::Regions => generator.regions as f32,
+            _ => continue,
+        };
+        if input.value != value {
+            input.set_value(value);
+        }
+    }
+}
 
-    // 🔹 3️⃣ Si on clique, même sans bouger, on agit avec la dernière position
-    if buttons.pressed(MouseButton::Left)
-        && (interaction == Interaction::Hovered || interaction == Interaction::Pressed)
-    {
-        if let Some(pos) = last_cursor.0 {
-            let x = pos.x.clamp(slider_x - slider_width / 2.0, slider_x + slider_width / 2.0);
-
-            let min_speed = MIN_SPEED;
-            let max_speed = MAX_SPEED;
-            let ratio = ((x - (slider_x - slider_width / 2.0)) / slider_width).clamp(0.0, 1.0);
-
-            if let Ok((mut handle_style, mut handle_color)) = param_set.p1().get_single_mut() {
-                // 🟨 Position du handle
-                let mut pos = ratio * BAR_WIDTH - (HANDLE_WIDTH * 2.0);
-                pos = pos.clamp(0.0, BAR_WIDTH - ((HANDLE_WIDTH * 2.0) + 2.0));
-                handle_style.left = Val::Percent(pos);
-
-                // 🟨 Calcul de la nouvelle vitesse
-                let new_speed = min_speed - ratio * (min_speed - max_speed);
-                timer_res.speed = new_speed;
-                timer_res
-                    .timer
-                    .set_duration(std::time::Duration::from_secs_f32(new_speed));
-
-                *handle_color = Color::srgb(1.0 - ratio, 1.0, 0.2).into();
-
-                // 🟨 Texte
-                if let Ok(mut text) = text_query.get_single_mut() {
-                    text.sections[0].value = format!("{:.2} s/étape", new_speed);
-                }
+// ⏱️ Le curseur règle la durée d’une étape et suit les changements venus d’ailleurs (session, réglages…)
+pub fn speed_slider_system(
+    mut events: EventReader<SliderChanged>,
+    mut sliders: Query<&mut Slider, With<SpeedSlider>>,
+    mut timer: ResMut<StepTimer>,
+) {
+    for event in events.read() {
+        if sliders.contains(event.slider) {
+            timer.set_speed(event.value);
+        }
+    }
+
+    if timer.is_changed() {
+        for mut slider in &mut sliders {
+            if slider.value != timer.speed {
+                slider.set_value(timer.speed);
             }
         }
     }
@@ -176,29 +380,514 @@ pub fn update_slider(
 
 
 pub fn mutation_checkbox_system(
-    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &MutationCheckbox), (Changed<Interaction>, With<Button>)>,
+    mut events: EventReader<ToggleChanged>,
+    checkbox_query: Query<&MutationCheckbox>,
     mut settings: ResMut<MutationSettings>,
 ) {
-    for (interaction, mut color, checkbox) in &mut interaction_query {
+    for event in events.read() {
+        match checkbox_query.get(event.toggle) {
+            Ok(MutationCheckbox::Blue) => settings.allow_blue = event.checked,
+            Ok(MutationCheckbox::Red) => settings.allow_red = event.checked,
+            Err(_) => {}
+        }
+    }
+}
+
+// Les cases suivent les réglages, y compris après le chargement d’une session, et les couleurs du thème
+pub fn update_mutation_checkboxes(
+    settings: Res<MutationSettings>,
+    theme: Res<Theme>,
+    mut checkbox_query: Query<(&mut Toggle, &MutationCheckbox)>,
+) {
+    if !settings.is_changed() && !theme.is_changed() {
+        return;
+    }
+    for (mut toggle, checkbox) in &mut checkbox_query {
+        let (checked, color) = match checkbox {
+            MutationCheckbox::Blue => (settings.allow_blue, theme.palette.blue),
+            MutationCheckbox::Red => (settings.allow_red, theme.palette.red),
+        };
+        toggle.set_if_neq(Toggle { checked, color: Some(color) });
+    }
+}
+
+pub fn graph_mode_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<GraphModeButton>)>,
+    buttons: Query<&Children, With<GraphModeButton>>,
+    mut labels: Query<&mut Localized>,
+    mut mode: ResMut<GraphMode>,
+) {
+    for interaction in &interaction_query {
         if *interaction == Interaction::Pressed {
-            match checkbox {
-                MutationCheckbox::Blue => {
-                    settings.allow_blue = !settings.allow_blue;
-                    *color = if settings.allow_blue {
-                        Color::srgb(0.2, 0.2, 0.8).into()
-                    } else {
-                        Color::srgb(0.3, 0.3, 0.3).into()
-                    };
+            *mode = match *mode {
+                GraphMode::Lines => GraphMode::Stacked,
+                GraphMode::Stacked => GraphMode::Lines,
+            };
+        }
+    }
+
+    if mode.is_changed()
+        && let Ok(children) = buttons.get_single()
+    {
+        set_button_label(children, &mut labels, match *mode {
+            GraphMode::Lines => "graph-lines",
+            GraphMode::Stacked => "graph-stacked",
+        });
+    }
+}
+
+pub fn update_population_graph(
+    history: Res<PopulationHistory>,
+    mode: Res<GraphMode>,
+    mut points: Query<(&GraphPoint, &mut Style), Without<GraphSegment>>,
+    mut segments: Query<(&GraphSegment, &mut Style), Without<GraphPoint>>,
+    mut legend: Query<&mut Text, With<GraphLegend>>,
+    loc: Res<Localization>,
+) {
+    if !history.is_changed() && !mode.is_changed() && !loc.is_changed() {
+        return;
+    }
+
+    // Les échantillons les plus récents sont alignés à droite du graphe
+    let offset = HISTORY_LEN - history.samples.len();
+    let sample = |column: usize| column.checked_sub(offset).and_then(|i| history.samples.get(i));
+
+    // Échelle verticale : population maximale visible
+    let max = history.samples.iter().map(|s| s.total).max().unwrap_or(0).max(1) as f32;
+
+    for (point, mut style) in &mut points {
+        match sample(point.column) {
+            Some(stats) if *mode == GraphMode::Lines => {
+                let value = match point.series {
+                    GraphSeries::Total => stats.total,
+                    GraphSeries::Strain(mutation) => stats.strain(mutation),
+                };
+                style.display = Display::Flex;
+                style.bottom = Val::Percent((value as f32 / max * 100.0).min(98.0));
+            }
+            _ => style.display = Display::None,
+        }
+    }
+
+    for (segment, mut style) in &mut segments {
+        match sample(segment.column) {
+            Some(stats) if *mode == GraphMode::Stacked && stats.total > 0 => {
+                // Parts cumulées des souches précédentes
+                let below: usize = MutationType::ALL
+                    .iter()
+                    .take_while(|m| **m != segment.mutation)
+                    .map(|m| stats.strain(*m))
+                    .sum();
+                let total = stats.total as f32;
+                style.display = Display::Flex;
+                style.bottom = Val::Percent(below as f32 / total * 100.0);
+                style.height = Val::Percent(stats.strain(segment.mutation) as f32 / total * 100.0);
+            }
+            _ => style.display = Display::None,
+        }
+    }
+
+    if let (Some(stats), Ok(mut text)) = (history.latest(), legend.get_single_mut()) {
+        text.sections[0].value = format!("{}  ", loc.t_with("graph-generation", &[("generation", stats.generation.into())]));
+        text.sections[1].value = format!("{}  ", loc.t_with("graph-total", &[("total", stats.total.into())]));
+        for (i, mutation) in MutationType::ALL.iter().enumerate() {
+            text.sections[2 + i].value = format!("{}  ", stats.strain(*mutation));
+        }
+    }
+}
+
+pub fn record_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<RecordButton>)>,
+    mut buttons: Query<(&Children, &mut Highlight), With<RecordButton>>,
+    mut labels: Query<&mut Localized>,
+    mut recorder: ResMut<StatsRecorder>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            if recorder.is_recording() {
+                recorder.stop();
+            } else if let Err(e) = recorder.start(&default_record_path()) {
+                error!("Impossible de démarrer l’enregistrement : {e}");
+            }
+        }
+    }
+
+    // 🔴 Libellé et couleur suivent l’état réel (y compris un enregistrement lancé avec --record)
+    if recorder.is_changed()
+        && let Ok((children, mut highlight)) = buttons.get_single_mut()
+    {
+        let recording = recorder.is_recording();
+        set_button_label(children, &mut labels, if recording { "record-stop" } else { "record-start" });
+        highlight.set_if_neq(if recording { Highlight::Alert } else { Highlight::Off });
+    }
+}
+
+pub fn stabilization_checkbox_system(
+    mut events: EventReader<ToggleChanged>,
+    checkbox_query: Query<&StabilizationCheckbox>,
+    mut settings: ResMut<StabilizationSettings>,
+) {
+    for event in events.read() {
+        match checkbox_query.get(event.toggle) {
+            Ok(StabilizationCheckbox::AutoPause) => settings.auto_pause = event.checked,
+            Ok(StabilizationCheckbox::AutoReset) => settings.auto_reset = event.checked,
+            Err(_) => {}
+        }
+    }
+}
+
+pub fn update_stabilization_text(
+    detector: Res<CycleDetector>,
+    mut text_query: Query<&mut Text, With<StabilizationText>>,
+    loc: Res<Localization>,
+) {
+    if !detector.is_changed() && !loc.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = match detector.detected() {
+            None => loc.t("state-running"),
+            Some(s) => {
+                let id = match s.kind {
+                    StabilizationKind::Empty => "state-empty",
+                    StabilizationKind::Static => "state-static",
+                    StabilizationKind::Periodic => "state-periodic",
+                };
+                loc.t_with(id, &[("generation", s.generation.into()), ("period", s.period.into())])
+            }
+        };
+    }
+}
+
+pub fn view_button_system(
+    interaction_query: Query<(&Interaction, &ViewButton), Changed<Interaction>>,
+    mut ev_view: EventWriter<ViewEvent>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ev_view.send(match button {
+                ViewButton::FitPattern => ViewEvent::FitPattern,
+                ViewButton::Reset => ViewEvent::Reset,
+            });
+        }
+    }
+}
+
+pub fn selection_button_system(
+    interaction_query: Query<(&Interaction, &SelectionButton), Changed<Interaction>>,
+    mut ev_action: EventWriter<SelectionAction>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ev_action.send(button.0);
+        }
+    }
+}
+
+pub fn save_session_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<SaveSessionButton>)>,
+    mut ev_save: EventWriter<SaveSessionEvent>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ev_save.send(SaveSessionEvent);
+        }
+    }
+}
+
+// 🌐 Choix de la langue ; la liste montre la langue courante, même choisie ailleurs (--lang)
+pub fn language_selector_system(
+    mut events: EventReader<DropdownChanged>,
+    mut selectors: Query<(Entity, &mut Dropdown), With<LanguageSelector>>,
+    mut loc: ResMut<Localization>,
+) {
+    for event in events.read() {
+        if selectors.contains(event.dropdown)
+            && let Some(&language) = Language::ALL.get(event.selected)
+            && loc.language() != language
+        {
+            loc.set_language(language);
+        }
+    }
+    if loc.is_changed() {
+        let selected = Language::ALL.iter().position(|l| *l == loc.language()).unwrap_or_default();
+        for (_, mut dropdown) in &mut selectors {
+            if dropdown.selected != selected {
+                dropdown.selected = selected;
+            }
+        }
+    }
+}
+
+// 🎨 Choix du mode de coloration ; le bouton actif est surligné
+pub fn color_mode_button_system(
+    interaction_query: Query<(&Interaction, &ColorModeButton), Changed<Interaction>>,
+    mut buttons: Query<(&ColorModeButton, &mut Highlight)>,
+    mut mode: ResMut<ColorMode>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed && *mode != button.0 {
+            *mode = button.0;
+        }
+    }
+    if mode.is_changed() {
+        for (button, mut highlight) in &mut buttons {
+            highlight.set_if_neq(if button.0 == *mode { Highlight::Active } else { Highlight::Off });
+        }
+    }
+}
+
+// 🌡️ Un clic sur le mode affiché masque la carte
+pub fn heatmap_button_system(
+    interaction_query: Query<(&Interaction, &HeatmapButton), Changed<Interaction>>,
+    mut buttons: Query<(&HeatmapButton, &mut Highlight)>,
+    mut settings: ResMut<HeatmapSettings>,
+    mut ev_heatmap: EventWriter<HeatmapEvent>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            HeatmapButton::Mode(mode) => {
+                settings.mode = if settings.mode == *mode { HeatmapMode::Off } else { *mode };
+            }
+            HeatmapButton::Opaque => settings.opaque = !settings.opaque,
+            HeatmapButton::Clear => {
+                ev_heatmap.send(HeatmapEvent::Clear);
+            }
+            HeatmapButton::Export => {
+                ev_heatmap.send(HeatmapEvent::Export);
+            }
+        }
+    }
+    if settings.is_changed() {
+        for (button, mut highlight) in &mut buttons {
+            let active = match button {
+                HeatmapButton::Mode(mode) => settings.mode == *mode,
+                HeatmapButton::Opaque => settings.opaque,
+                _ => false,
+            };
+            highlight.set_if_neq(if active { Highlight::Active } else { Highlight::Off });
+        }
+    }
+}
+
+pub fn export_button_system(
+    interaction_query: Query<(&Interaction, &ExportButton), Changed<Interaction>>,
+    mut settings: ResMut<ExportSettings>,
+    mut ev_export: EventWriter<ExportEvent>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                ExportButton::Image => {
+                    ev_export.send(ExportEvent::SaveImage);
                 }
-                MutationCheckbox::Red => {
-                    settings.allow_red = !settings.allow_red;
-                    *color = if settings.allow_red {
-                        Color::srgb(0.8, 0.2, 0.2).into()
-                    } else {
-                        Color::srgb(0.3, 0.3, 0.3).into()
+                ExportButton::Animation => {
+                    ev_export.send(ExportEvent::ToggleAnimation);
+                }
+                ExportButton::Format => {
+                    settings.animation_format = match settings.animation_format {
+                        ImageFormat::Gif => ImageFormat::Apng,
+                        _ => ImageFormat::Gif,
                     };
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// 🎞️ Nombre de générations capturées, réglé dans le panneau ou fixé au lancement par --animation-frames
+pub fn animation_frames_system(
+    mut events: EventReader<NumberChanged>,
+    mut inputs: Query<(Entity, &mut NumberInput), With<AnimationFramesInput>>,
+    mut settings: ResMut<ExportSettings>,
+) {
+    for event in events.read() {
+        if inputs.contains(event.input) {
+            settings.frames = event.value as usize;
+        }
+    }
+    if settings.is_changed() {
+        for (_, mut input) in &mut inputs {
+            if input.value as usize != settings.frames {
+                input.set_value(settings.frames as f32);
+            }
+        }
+    }
+}
+
+// 🎞️ Progression de la capture et format choisi
+pub fn update_export_buttons(
+    settings: Res<ExportSettings>,
+    capture: Res<AnimationCapture>,
+    mut buttons: Query<(&ExportButton, &Children, &mut Highlight)>,
+    mut texts: Query<&mut Text>,
+    loc: Res<Localization>,
+) {
+    if !settings.is_changed() && !capture.is_changed() && !loc.is_changed() {
+        return;
+    }
+    for (button, children, mut highlight) in &mut buttons {
+        let label = match button {
+            ExportButton::Image => continue,
+            ExportButton::Animation if capture.recording => format!("{}/{}", capture.frames.len(), settings.frames),
+            ExportButton::Animation => loc.t("export-animate"),
+            ExportButton::Format => settings.animation_format.extension().to_uppercase(),
+        };
+        if *button == ExportButton::Animation {
+            highlight.set_if_neq(if capture.recording { Highlight::Alert } else { Highlight::Off });
+        }
+        for &child in children {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.sections[0].value = label.clone();
+            }
+        }
+    }
+}
+
+pub fn census_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CensusButton>)>,
+    mut ev_census: EventWriter<CensusRequestEvent>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ev_census.send(CensusRequestEvent);
+        }
+    }
+}
+
+pub fn update_census_text(
+    census: Res<ObjectCensus>,
+    mut text_query: Query<&mut Text, With<CensusText>>,
+    loc: Res<Localization>,
+) {
+    if !census.is_changed() && !loc.is_changed() {
+        return;
+    }
+    let Some(result) = &census.0 else {
+        return;
+    };
+    if let Ok(mut text) = text_query.get_single_mut() {
+        let mut value = loc.t_with("census-generation", &[("generation", result.generation.into())]);
+        if result.objects.is_empty() {
+            value.push('\n');
+            value.push_str(&loc.t("census-empty"));
+        }
+        for (name, count) in &result.objects {
+            value.push_str(&format!("\n{count} × {name}"));
+        }
+        text.sections[0].value = value;
+    }
+}
+
+pub fn library_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<LibraryButton>)>,
+    mut panel_query: Query<&mut Visibility, With<LibraryPanel>>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut visibility) = panel_query.get_single_mut()
+        {
+            *visibility = match *visibility {
+                Visibility::Hidden => Visibility::Inherited,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+// Reconstruit la liste quand un fichier de motif apparaît, change ou disparaît, et à chaque frappe dans le filtre
+pub fn update_library_list(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    library: Res<PatternLibrary>,
+    theme: Res<Theme>,
+    loc: Res<Localization>,
+    filter_query: Query<Ref<TextField>, With<LibraryFilter>>,
+    mut content_query: Query<(Entity, &mut Style), With<LibraryContent>>,
+) {
+    let filter = filter_query.get_single().ok();
+    let filter_changed = filter.as_ref().is_some_and(|f| f.is_changed());
+    if !library.is_changed() && !loc.is_changed() && !filter_changed {
+        return;
+    }
+    let filter = filter.map(|f| f.text.clone()).unwrap_or_default();
+    let Ok((content, mut style)) = content_query.get_single_mut() else {
+        return;
+    };
+    style.top = Val::Px(0.0);
+    commands.entity(content).despawn_descendants().with_children(|content| {
+        spawn_library_entries(content, asset_server.load(FONT), &theme, &loc, &library, &filter);
+    });
+}
+
+// Un clic sur un motif le met en attente de collage (voir `selection`), la grille redevient visible
+pub fn library_entry_system(
+    interaction_query: Query<(&Interaction, &LibraryEntryButton), Changed<Interaction>>,
+    library: Res<PatternLibrary>,
+    mut selection: ResMut<Selection>,
+    mut panel_query: Query<&mut Visibility, With<LibraryPanel>>,
+) {
+    for (interaction, entry) in &interaction_query {
+        if *interaction == Interaction::Pressed
+            && let Some(entry) = library.entries.get(entry.0)
+        {
+            selection.floating = Some(entry.pattern.clone());
+            if let Ok(mut visibility) = panel_query.get_single_mut() {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+pub fn library_scroll_system(
+    mut wheel: EventReader<MouseWheel>,
+    list_query: Query<(&Interaction, &Node), With<LibraryList>>,
+    entry_query: Query<&Interaction, With<LibraryEntryButton>>,
+    mut content_query: Query<(&mut Style, &Node), With<LibraryContent>>,
+) {
+    let scroll: f32 = wheel
+        .read()
+        .map(|e| match e.unit {
+            MouseScrollUnit::Line => e.y * 30.0,
+            MouseScrollUnit::Pixel => e.y,
+        })
+        .sum();
+    let (Ok((interaction, list)), Ok((mut style, content))) = (list_query.get_single(), content_query.get_single_mut()) else {
+        return;
+    };
+    // les boutons des motifs masquent la liste au survol : l’un ou l’autre suffit
+    let hovered = *interaction != Interaction::None || entry_query.iter().any(|i| *i != Interaction::None);
+    if scroll == 0.0 || !hovered {
+        return;
+    }
+
+    let max_offset = (content.size().y - list.size().y).max(0.0);
+    let top = match style.top {
+        Val::Px(top) => top,
+        _ => 0.0,
+    };
+    style.top = Val::Px((top + scroll).clamp(-max_offset, 0.0));
+}
+
+// Reconstruit l’aide quand les touches ou la langue changent
+pub fn update_help_overlay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    bindings: Res<KeyBindings>,
+    theme: Res<Theme>,
+    loc: Res<Localization>,
+    content_query: Query<Entity, With<HelpContent>>,
+) {
+    if !bindings.is_changed() && !loc.is_changed() {
+        return;
+    }
+    let Ok(content) = content_query.get_single() else {
+        return;
+    };
+    commands.entity(content).despawn_descendants().with_children(|content| {
+        spawn_help_entries(content, asset_server.load(FONT), &theme, &loc, &bindings);
+    });
+}
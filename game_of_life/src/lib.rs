@@ -0,0 +1,34 @@
+pub mod cell;
+pub mod grid;
+pub mod systems;
+pub mod interface;
+pub mod visual_elements;
+pub mod mutation_setting;
+pub mod stats;
+pub mod recorder;
+pub mod detection;
+pub mod census;
+pub mod batch;
+pub mod sweep;
+pub mod rule;
+pub mod pattern;
+pub mod cli;
+pub mod camera;
+pub mod selection;
+pub mod library;
+pub mod session;
+pub mod toast;
+pub mod file_drop;
+pub mod export;
+pub mod heatmap;
+pub mod theme;
+pub mod locale;
+pub mod widgets;
+pub mod shortcuts;
+pub mod edits;
+pub mod settings;
+pub mod generator;
+pub mod draw;
+pub mod scripting;
+pub mod console;
+pub mod server;
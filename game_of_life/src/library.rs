@@ -0,0 +1,173 @@
+use bevy::asset::io::file::FileAssetReader;
+use bevy::prelude::*;
+use bevy::render::{
+    render_asset::RenderAssetUsages,
+    render_resource::{Extent3d, TextureDimension, TextureFormat}
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use crate::{pattern::Pattern, theme::Theme};
+
+/// Côté des vignettes, en pixels
+pub const THUMBNAIL_SIZE: u32 = 48;
+/// Catégorie des motifs du dossier personnel
+pub const USER_CATEGORY: &str = "mes motifs";
+const RESCAN_INTERVAL: f32 = 1.0;
+const EXTENSIONS: [&str; 3] = ["rle", "cells", "txt"];
+
+pub struct LibraryEntry {
+    pub name: String,
+    pub category: String,
+    pub pattern: Pattern,
+    pub thumbnail: Handle<Image>,
+}
+
+/// Motifs fournis (`assets/patterns/<catégorie>/`) et motifs personnels,
+/// relus dès qu’un fichier apparaît, change ou disparaît
+#[derive(Resource)]
+pub struct PatternLibrary {
+    pub entries: Vec<LibraryEntry>,
+    bundled_dir: PathBuf,
+    user_dir: Option<PathBuf>,
+    signature: Option<Vec<(PathBuf, Option<SystemTime>)>>,
+    timer: Timer,
+}
+
+impl PatternLibrary {
+    pub fn new(user_dir: Option<PathBuf>) -> Self {
+        // on crée le dossier personnel pour que l’utilisateur sache où déposer ses fichiers
+        if let Some(dir) = &user_dir
+            && let Err(e) = fs::create_dir_all(dir)
+        {
+            warn!("Impossible de créer {} : {e}", dir.display());
+        }
+        Self {
+            entries: Vec::new(),
+            bundled_dir: FileAssetReader::get_base_path().join("assets").join("patterns"),
+            user_dir,
+            signature: None,
+            timer: Timer::from_seconds(RESCAN_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    pub fn user_dir(&self) -> Option<&Path> {
+        self.user_dir.as_deref()
+    }
+
+    /// Fichiers de motifs et leur catégorie (sous-dossier pour les motifs fournis)
+    fn files(&self) -> Vec<(PathBuf, String)> {
+        let mut files = Vec::new();
+        collect_files(&self.bundled_dir, None, &mut files);
+        if let Some(dir) = &self.user_dir {
+            collect_files(dir, Some(USER_CATEGORY), &mut files);
+        }
+        files
+    }
+}
+
+/// Dossier personnel par défaut : `<données utilisateur>/game_of_life/patterns`
+pub fn default_user_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("game_of_life").join("patterns"))
+}
+
+fn collect_files(dir: &Path, category: Option<&str>, out: &mut Vec<(PathBuf, String)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            // un seul niveau : le nom du sous-dossier donne la catégorie
+            if category.is_none() {
+                let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+                collect_files(&path, Some(&name), out);
+            }
+        } else if path
+            .extension()
+            .is_some_and(|e| EXTENSIONS.contains(&e.to_string_lossy().to_ascii_lowercase().as_str()))
+        {
+            out.push((path, category.unwrap_or("divers").to_string()));
+        }
+    }
+}
+
+/// Vignette carrée : le motif est centré et mis à l’échelle pour remplir THUMBNAIL_SIZE pixels
+pub fn thumbnail(pattern: &Pattern, theme: &Theme) -> Image {
+    let size = THUMBNAIL_SIZE as usize;
+    let mut data = theme.ui.dead_cell.to_srgba().to_u8_array().repeat(size * size);
+
+    let scale = size as f32 / pattern.width.max(pattern.height).max(1) as f32; // pixels par cellule
+    let offset_x = (size as f32 - pattern.width as f32 * scale) / 2.0;
+    let offset_y = (size as f32 - pattern.height as f32 * scale) / 2.0;
+    let span = |offset: f32, i: usize| {
+        let start = (offset + i as f32 * scale) as usize;
+        let end = ((offset + (i + 1) as f32 * scale) as usize).max(start + 1).min(size);
+        start.min(size)..end
+    };
+
+    for &(x, y, mutation) in &pattern.cells {
+        let color = theme.strain(mutation).to_srgba().to_u8_array();
+        for py in span(offset_y, y) {
+            for px in span(offset_x, x) {
+                let i = (py * size + px) * 4;
+                data[i..i + 4].copy_from_slice(&color);
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d { width: THUMBNAIL_SIZE, height: THUMBNAIL_SIZE, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+// 📚 Relit les dossiers de motifs régulièrement, sans redémarrer l’application
+pub fn refresh_library_system(
+    time: Res<Time>,
+    mut library: ResMut<PatternLibrary>,
+    mut images: ResMut<Assets<Image>>,
+    theme: Res<Theme>,
+) {
+    // le minuteur ne compte pas comme une modification de la bibliothèque
+    let due = library.bypass_change_detection().timer.tick(time.delta()).just_finished();
+    if !due && library.signature.is_some() && !theme.is_changed() {
+        return;
+    }
+
+    let files = library.files();
+    let signature: Vec<_> = files
+        .iter()
+        .map(|(path, _)| (path.clone(), fs::metadata(path).and_then(|m| m.modified()).ok()))
+        .collect();
+    if library.signature.as_ref() == Some(&signature) && !theme.is_changed() {
+        return;
+    }
+
+    let mut entries: Vec<LibraryEntry> = files
+        .into_iter()
+        .filter_map(|(path, category)| match Pattern::load(&path) {
+            Ok(pattern) => Some(LibraryEntry {
+                name: pattern.name.clone().unwrap_or_else(|| {
+                    path.file_stem().unwrap_or_default().to_string_lossy().replace('_', " ")
+                }),
+                category,
+                thumbnail: images.add(thumbnail(&pattern, &theme)),
+                pattern,
+            }),
+            Err(e) => {
+                warn!("Motif ignoré {} : {e}", path.display());
+                None
+            }
+        })
+        .collect();
+    // motifs fournis par catégorie, puis motifs personnels
+    entries.sort_by(|a, b| {
+        (a.category == USER_CATEGORY, &a.category, &a.name).cmp(&(b.category == USER_CATEGORY, &b.category, &b.name))
+    });
+
+    library.entries = entries;
+    library.signature = Some(signature);
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use clap::ValueEnum;
+use fluent_bundle::concurrent::FluentBundle;
+use serde::{Deserialize, Serialize};
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use unic_langid::LanguageIdentifier;
+
+/// Langues de l’interface
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Language {
+    /// Langue de référence : tout message manquant ailleurs est pris ici
+    #[default]
+    Fr,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Fr, Language::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Fr => "fr",
+            Language::En => "en",
+        }
+    }
+
+    /// Nom de la langue, dans la langue elle-même
+    pub fn message(self) -> &'static str {
+        match self {
+            Language::Fr => "language-fr",
+            Language::En => "language-en",
+        }
+    }
+
+    /// Messages de `assets/locales/<code>/main.ftl`, intégrés à l’exécutable
+    fn source(self) -> &'static str {
+        match self {
+            Language::Fr => include_str!("../assets/locales/fr/main.ftl"),
+            Language::En => include_str!("../assets/locales/en/main.ftl"),
+        }
+    }
+
+    /// Langue du système (« fr-FR », « fr_CA.UTF-8 »…), l’anglais pour toutes les autres
+    pub fn detect() -> Language {
+        match sys_locale::get_locale() {
+            Some(locale) if locale.to_ascii_lowercase().starts_with("fr") => Language::Fr,
+            _ => Language::En,
+        }
+    }
+}
+
+/// Traductions de la langue choisie ; passer par `t` pour tout texte affiché
+#[derive(Resource)]
+pub struct Localization {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Localization {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            bundle: bundle(language),
+            fallback: bundle(Language::Fr),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        if language != self.language {
+            *self = Localization::new(language);
+        }
+    }
+
+    /// Texte du message `id` (l’identifiant lui-même s’il n’existe dans aucune langue)
+    pub fn t(&self, id: &str) -> String {
+        self.t_with(id, &[])
+    }
+
+    /// Texte du message `id` avec ses variables (`{ $nom }` dans les fichiers .ftl)
+    pub fn t_with(&self, id: &str, args: &[(&str, FluentValue)]) -> String {
+        self.get(id, args).unwrap_or_else(|| id.to_string())
+    }
+
+    /// Nom affiché d’une catégorie de motifs ; un dossier personnel garde son nom
+    pub fn category(&self, category: &str) -> String {
+        let id = format!("category-{}", category.replace(' ', "-"));
+        self.get(&id, &[]).unwrap_or_else(|| category.to_string())
+    }
+
+    fn get(&self, id: &str, args: &[(&str, FluentValue)]) -> Option<String> {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        [&self.bundle, &self.fallback].into_iter().find_map(|bundle| {
+            let pattern = bundle.get_message(id)?.value()?;
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+            for e in errors {
+                warn!("Message « {id} » ({}) : {e}", self.language.code());
+            }
+            Some(text.into_owned())
+        })
+    }
+}
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let resource = FluentResource::try_new(language.source().to_string()).unwrap_or_else(|(resource, errors)| {
+        for e in errors {
+            warn!("Fichier de traduction {} : {e:?}", language.code());
+        }
+        resource
+    });
+    let id: LanguageIdentifier = language.code().parse().expect("code de langue valide");
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // pas de caractères d’isolation bidirectionnelle autour des variables : la police ne les affiche pas
+    bundle.set_use_isolating(false);
+    if let Err(errors) = bundle.add_resource(resource) {
+        for e in errors {
+            warn!("Fichier de traduction {} : {e}", language.code());
+        }
+    }
+    bundle
+}
+
+/// Texte fixe de l’interface, réécrit à chaque changement de langue
+#[derive(Component)]
+pub struct Localized(pub &'static str);
+
+/// Liste déroulante du choix de la langue (options dans l’ordre de `Language::ALL`)
+#[derive(Component)]
+pub struct LanguageSelector;
+
+// 🌐 Textes fixes traduits à leur apparition, quand leur message change, puis à chaque changement de langue
+pub fn localize_texts(loc: Res<Localization>, mut texts: Query<(Ref<Localized>, &mut Text)>) {
+    for (localized, mut text) in &mut texts {
+        if loc.is_changed() || localized.is_changed() {
+            text.sections[0].value = loc.t(localized.0);
+        }
+    }
+}
@@ -1,32 +1,231 @@
-mod cell;
-mod systems;
-mod interface;
-mod visual_elements;
-mod mutation_setting;
-
 use bevy::{prelude::*};
-use systems::{set_grid,update_cells,StepTimer,DEFAULT_SPEED};
-use interface::{set_window,update_slider, button_system, reset_grid_system,mutation_checkbox_system, ResetGridEvent,LastCursorPos};
+use clap::{CommandFactory, FromArgMatches};
+use game_of_life::{
+    systems, interface, mutation_setting, stats, recorder, detection, census, cli, grid, pattern, camera, selection, library, session, toast, file_drop, export, heatmap, locale, widgets, shortcuts, edits, settings, generator, draw, scripting, console, server
+};
+use systems::{spawn_grid,update_cells,color_cells,update_grid_lines,SimRng,InitialPattern,GridLines};
+use interface::{set_window,speed_slider_system, button_system, pause_button_system, reset_grid_system,mutation_checkbox_system, update_mutation_checkboxes, save_session_button_system, graph_mode_button_system, update_population_graph, record_button_system, stabilization_checkbox_system, update_stabilization_text, census_button_system, update_census_text, view_button_system, selection_button_system, library_button_system, update_library_list, library_entry_system, library_scroll_system, export_button_system, update_export_buttons, color_mode_button_system, heatmap_button_system, language_selector_system, animation_frames_system, generator_control_system, draw_control_system, update_help_overlay, ResetGridEvent,GraphMode};
 use mutation_setting::{MutationSettings};
+use stats::PopulationHistory;
+use recorder::{StatsRecorder, default_record_path, record_stats_system};
+use detection::{CycleDetector, StabilizationSettings, StabilizedEvent, detect_stabilization_system, stabilization_response_system};
+use census::{CensusLibrary, CensusRequestEvent, ObjectCensus, census_system};
+use cli::Cli;
+use grid::{Grid, GridConfig};
+use pattern::Pattern;
+use camera::{ViewEvent, update_grid_viewport, zoom_camera, pan_camera, view_event_system};
+use session::{SaveSessionEvent, save_session_system};
+use toast::{Toast, show_toasts, expire_toasts};
+use file_drop::{OpenFileEvent, file_drop_system};
+use heatmap::{ActivityHeatmap, HeatmapEvent, HeatmapSettings, accumulate_heatmap, heatmap_event_system, update_heatmap_overlay};
+use export::{AnimationCapture, ExportEvent, export_system};
+use locale::{Language, Localization, localize_texts};
+use widgets::{WidgetFocus, focus_system};
+use widgets::button::update_button_colors;
+use widgets::toggle::{ToggleChanged, toggle_system, update_toggles};
+use widgets::slider::{SliderChanged, slider_drag_system, slider_keyboard_system, update_sliders};
+use widgets::number::{NumberChanged, number_step_system, number_keyboard_system, update_number_inputs};
+use widgets::dropdown::{DropdownChanged, dropdown_system, update_dropdowns};
+use widgets::text_field::{TextChanged, text_field_system, update_text_fields};
+use widgets::section::{section_system, update_sections};
+use library::{PatternLibrary, default_user_dir, refresh_library_system};
+use shortcuts::{Action, HeldActions, shortcut_system, action_system};
+use edits::{EditHistory, edit_action_system};
+use generator::Generator;
+use draw::{DrawSettings, draw_system};
+use console::{Console, ConsoleCommand, console_input_system, console_command_system, console_log_system, update_console};
+use server::{ControlServer, DEFAULT_PORT, server_system, stream_system};
+use scripting::{ScriptEvent, ScriptHost, StartupScript, script_system, startup_script_system};
+use settings::{SettingsFile, UserSettings, default_settings_path, sync_settings, save_settings_system, reset_settings_button_system};
+use selection::{Selection, SelectionAction, PatternClipboard, selection_mouse_system, selection_action_system, update_selection_overlay};
 
 fn main() {
-    App::new()
-        .insert_resource(StepTimer{
-            timer : Timer::from_seconds(DEFAULT_SPEED, TimerMode::Repeating),
-            speed: DEFAULT_SPEED,
-        })
-        .insert_resource(LastCursorPos::default())
-        .insert_resource(MutationSettings::default())
-        .add_plugins(DefaultPlugins)
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    // ⚙️ Réglages du lancement précédent, complétés par la ligne de commande
+    let settings_path = default_settings_path();
+    let mut settings = match settings_path.as_deref().filter(|path| path.exists()) {
+        Some(path) => UserSettings::load(path).unwrap_or_else(|e| {
+            eprintln!("Réglages illisibles ({}), valeurs par défaut utilisées : {e}", path.display());
+            UserSettings::default()
+        }),
+        None => UserSettings::default(),
+    };
+    settings.merge_cli(&mut cli, &matches);
+    let window = settings.window();
+
+    // 🧩 `--pattern <fichier>` : motif de départ
+    let pattern = match &cli.pattern {
+        Some(path) => match Pattern::load(path) {
+            Ok(pattern) => Some(pattern),
+            Err(e) => {
+                eprintln!("Impossible de charger {} : {e}", path.display());
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    // 🎨 `--theme`, `--palette`, `--theme-file` : couleurs de la grille et de l’interface
+    let theme = match cli.appearance.theme() {
+        Ok(theme) => theme,
+        Err(e) => {
+            eprintln!("Thème illisible : {e}");
+            std::process::exit(1);
+        }
+    };
+
+    // ⌨️ `--keys` : raccourcis clavier
+    let bindings = match cli.key_bindings() {
+        Ok(bindings) => bindings,
+        Err(e) => {
+            eprintln!("Raccourcis illisibles : {e}");
+            std::process::exit(1);
+        }
+    };
+
+    // 📝 `--record [fichier]` : enregistrer les statistiques dès le lancement
+    let mut recorder = StatsRecorder::default();
+    if let Some(path) = &cli.record {
+        let path = path.clone().unwrap_or_else(default_record_path);
+        if let Err(e) = recorder.start(&path) {
+            eprintln!("Impossible d’enregistrer dans {} : {e}", path.display());
+        }
+    }
+
+    // 🌐 `--server [port]` : serveur de contrôle local
+    let server = match cli.server {
+        Some(port) => match ControlServer::start(port.unwrap_or(DEFAULT_PORT)) {
+            Ok(server) => {
+                println!("Serveur de contrôle : http://{}", server.address);
+                Some(server)
+            }
+            Err(e) => {
+                eprintln!("Impossible de démarrer le serveur de contrôle : {e}");
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    let mut app = App::new();
+    app
+        .insert_resource(cli.step_timer())
+        .insert_resource(cli.rng())
+        .insert_resource(cli.simulation.grid_config(pattern.as_ref().and_then(|p| p.rule)))
+        .insert_resource(cli.simulation.mutation_settings())
+        .insert_resource(cli.simulation.generator.clone())
+        .insert_resource(InitialPattern(pattern))
+        .insert_resource(WidgetFocus::default())
+        .insert_resource(bindings)
+        .insert_resource(HeldActions::default())
+        .insert_resource(GridLines::default())
+        .insert_resource(EditHistory::default())
+        .insert_resource(PopulationHistory::default())
+        .insert_resource(GraphMode::default())
+        .insert_resource(recorder)
+        .insert_resource(CycleDetector::default())
+        .insert_resource(StabilizationSettings::default())
+        .insert_resource(CensusLibrary::default())
+        .insert_resource(ObjectCensus::default())
+        .insert_resource(Selection::default())
+        .insert_resource(PatternClipboard::default())
+        .insert_resource(DrawSettings::default())
+        .insert_resource(ScriptHost::default())
+        .insert_resource(Console::default())
+        .insert_resource(StartupScript(cli.script.clone()))
+        .insert_resource(PatternLibrary::new(cli.patterns_dir.clone().or_else(default_user_dir)))
+        .insert_resource(ClearColor(theme.ui.background))
+        .insert_resource(theme)
+        // 🌐 `--lang` : langue de l’interface, sinon celle du système
+        .insert_resource(Localization::new(cli.lang.unwrap_or_else(Language::detect)))
+        .insert_resource(cli.color_mode)
+        .insert_resource(cli.export_settings())
+        .insert_resource(AnimationCapture::default())
+        .insert_resource(ActivityHeatmap::default())
+        .insert_resource(HeatmapSettings::default())
+        .insert_resource(settings)
+        .insert_resource(SettingsFile(settings_path))
+        .add_plugins(DefaultPlugins.set(WindowPlugin { primary_window: Some(window), ..default() }))
         .add_event::<ResetGridEvent>() // <- très important
-        .add_systems(Startup, (set_window,setup).chain())
+        .add_event::<StabilizedEvent>()
+        .add_event::<CensusRequestEvent>()
+        .add_event::<ViewEvent>()
+        .add_event::<Action>()
+        .add_event::<OpenFileEvent>()
+        .add_event::<SelectionAction>()
+        .add_event::<SaveSessionEvent>()
+        .add_event::<Toast>()
+        .add_event::<ExportEvent>()
+        .add_event::<HeatmapEvent>()
+        .add_event::<SliderChanged>()
+        .add_event::<ToggleChanged>()
+        .add_event::<NumberChanged>()
+        .add_event::<DropdownChanged>()
+        .add_event::<TextChanged>()
+        .add_event::<ScriptEvent>()
+        .add_event::<ConsoleCommand>()
+        .add_systems(Startup, (set_window,setup,startup_script_system).chain())
 
-        .add_systems(Update, (update_slider,update_cells,button_system,reset_grid_system,mutation_checkbox_system).chain())
-        .run();
+        // 🧩 widgets d’abord : leurs événements sont lus dans la même frame
+        .add_systems(Update, (slider_drag_system, slider_keyboard_system, toggle_system, number_step_system, number_keyboard_system, dropdown_system, text_field_system, section_system).chain())
+        // ⌨️ raccourcis ensuite : ils agissent avant les systèmes qu’ils pilotent
+        .add_systems(Update, (shortcut_system, action_system).chain().after(section_system).before(update_grid_viewport).before(speed_slider_system))
+        .add_systems(Update, (speed_slider_system,update_cells,button_system,pause_button_system,reset_grid_system,mutation_checkbox_system).chain().after(section_system))
+        .add_systems(Update, (graph_mode_button_system, update_population_graph, record_button_system, record_stats_system).chain().after(reset_grid_system))
+        .add_systems(Update, (detect_stabilization_system, stabilization_response_system, stabilization_checkbox_system, update_stabilization_text).chain().after(reset_grid_system))
+        .add_systems(Update, (census_button_system, census_system, update_census_text).chain().after(detect_stabilization_system).after(record_stats_system))
+        .add_systems(Update, (update_grid_viewport, view_button_system, view_event_system, zoom_camera, pan_camera).chain().after(slider_keyboard_system))
+        .add_systems(Update, (refresh_library_system, update_library_list, library_button_system, library_entry_system, library_scroll_system).chain().before(selection_mouse_system).before(zoom_camera))
+        .add_systems(Update, (selection_button_system, selection_mouse_system, draw_system, selection_action_system, edit_action_system, update_selection_overlay).chain().after(pan_camera).after(update_cells).before(detect_stabilization_system))
+        .add_systems(Update, (file_drop_system, save_session_button_system, save_session_system, update_mutation_checkboxes).chain().after(edit_action_system).before(detect_stabilization_system))
+        .add_systems(Update, (export_button_system, animation_frames_system, export_system, update_export_buttons).chain().after(save_session_system).before(show_toasts))
+        .add_systems(Update, (color_mode_button_system, update_grid_lines, color_cells).chain().after(heatmap_button_system).after(update_mutation_checkboxes).after(reset_grid_system).after(stabilization_response_system))
+        .add_systems(Update, (accumulate_heatmap, heatmap_button_system, heatmap_event_system, update_heatmap_overlay).chain().after(update_mutation_checkboxes).after(reset_grid_system).after(stabilization_response_system).before(show_toasts))
+        .add_systems(Update, (show_toasts, expire_toasts).chain().after(file_drop_system))
+        // textes fixes d’abord : « Animer » et le format d’export sont ensuite remplacés par leur état
+        .add_systems(Update, (language_selector_system, localize_texts, update_help_overlay).chain().after(section_system).before(update_export_buttons))
+        // le focus change après les raccourcis : Échap ferme d’abord le widget actif, pas la sélection
+        .add_systems(Update, focus_system.after(shortcut_system).after(pan_camera).after(section_system))
+        .add_systems(Update, (update_sliders, update_toggles, update_number_inputs, update_dropdowns, update_text_fields, update_sections, update_button_colors).after(focus_system).after(update_export_buttons).after(color_cells))
+        .add_systems(Update, generator_control_system.after(section_system).before(reset_grid_system))
+        .add_systems(Update, draw_control_system.after(action_system).before(selection_mouse_system))
+        .add_systems(Update, reset_settings_button_system.before(speed_slider_system).before(update_mutation_checkboxes).before(language_selector_system).before(generator_control_system))
+        // 📜 après les générations et les modifications de la frame, avant ce qui les observe
+        .add_systems(Update, script_system.after(update_cells).after(edit_action_system).before(update_mutation_checkboxes).before(detect_stabilization_system).before(record_stats_system).before(color_cells).before(show_toasts))
+        // 🖥️ console : saisie avant les raccourcis (qu’elle suspend), commandes avant la simulation
+        .add_systems(Update, (console_input_system, console_command_system).chain().after(section_system).before(shortcut_system).before(speed_slider_system))
+        .add_systems(Update, (console_log_system, update_console).chain().after(script_system).after(file_drop_system).after(focus_system))
+        // 🌐 requêtes du serveur avant la simulation, flux une fois la grille de la frame connue
+        .add_systems(Update, server_system.run_if(resource_exists::<ControlServer>).after(console_command_system).before(speed_slider_system))
+        .add_systems(Update, stream_system.run_if(resource_exists::<ControlServer>).after(script_system).after(file_drop_system).after(reset_grid_system).after(edit_action_system))
+        .add_systems(Last, (sync_settings, save_settings_system).chain());
+    if let Some(server) = server {
+        app.insert_resource(server);
+    }
+    app.run();
 }
 
-fn setup(mut commands: Commands,settings: Res<MutationSettings>) {
-    set_grid(&mut commands,&settings); // Appel de ta fonction utilitaire
+#[allow(clippy::too_many_arguments)]
+fn setup(
+    mut commands: Commands,
+    settings: Res<MutationSettings>,
+    config: Res<GridConfig>,
+    generator: Res<Generator>,
+    mut rng: ResMut<SimRng>,
+    initial: Res<InitialPattern>,
+    mut history: ResMut<PopulationHistory>,
+    mut edits: ResMut<EditHistory>,
+    mut ev_view: EventWriter<ViewEvent>,
+) {
+    let grid = match &initial.0 {
+        Some(pattern) => Grid::with_pattern(&config, pattern),
+        None => generator.generate(&config, &settings, &mut rng.0),
+    };
+    history.push(spawn_grid(&mut commands, &grid));
+    edits.set_start(grid); // « Revenir au départ » ramène à cette grille
+    ev_view.send(ViewEvent::Reset); // toute la grille visible dès le départ
 }
 
 
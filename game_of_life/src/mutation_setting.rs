@@ -1,9 +1,12 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource)]
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MutationSettings {
     pub allow_blue: bool,
     pub allow_red: bool,
+    pub blue_probability: f32, // probabilité qu’une naissance soit bleue
+    pub red_probability: f32,  // probabilité qu’une naissance soit rouge
 }
 
 #[derive(Component)]
@@ -17,6 +20,8 @@ impl Default for MutationSettings {
         Self {
             allow_blue: true,
             allow_red: true,
+            blue_probability: 0.1,
+            red_probability: 0.1,
         }
     }
 }
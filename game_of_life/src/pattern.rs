@@ -0,0 +1,489 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::{
+    cell::MutationType,
+    rule::Rule
+};
+
+/// Motif chargé depuis un fichier : cellules vivantes relatives au coin haut-gauche
+///
+/// En RLE, les états étendus `A`, `B` et `C` codent respectivement une cellule
+/// normale, bleue et rouge ; `o` reste une cellule normale.
+#[derive(Clone, Debug, Default)]
+pub struct Pattern {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<(usize, usize, MutationType)>,
+    pub rule: Option<Rule>,
+    /// Nom donné par la ligne `#N` d’un fichier RLE
+    pub name: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    Io(io::Error),
+    Parse(String),
+    UnsupportedFormat(String),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::Io(e) => write!(f, "lecture impossible : {e}"),
+            PatternError::Parse(msg) => write!(f, "motif invalide : {msg}"),
+            PatternError::UnsupportedFormat(ext) => write!(f, "format non pris en charge : {ext}"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl From<io::Error> for PatternError {
+    fn from(e: io::Error) -> Self {
+        PatternError::Io(e)
+    }
+}
+
+/// Au-delà, le motif ne tiendrait de toute façon pas dans une grille affichable
+const MAX_CELLS: usize = 1_000_000;
+/// Largeur et hauteur maximales d’un motif RLE
+const MAX_SIZE: usize = 100_000;
+
+enum MacroNode {
+    Leaf(Vec<(u64, u64, MutationType)>),
+    States([usize; 4]),
+    Inner(u32, [usize; 4]),
+}
+
+impl Pattern {
+    /// Charge un fichier `.rle`, `.cells` ou `.txt` (plaintext), ou `.mc` (macrocell de Golly)
+    pub fn load(path: &Path) -> Result<Self, PatternError> {
+        let text = fs::read_to_string(path)?;
+        let ext = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        match ext.as_str() {
+            "rle" => Pattern::parse_rle(&text),
+            "cells" | "txt" => Pattern::parse_plaintext(&text),
+            "mc" => Pattern::parse_macrocell(&text),
+            _ => Err(PatternError::UnsupportedFormat(ext)),
+        }
+    }
+
+    pub fn parse_rle(text: &str) -> Result<Self, PatternError> {
+        let mut pattern = Pattern::default();
+        let mut body = String::new();
+        let mut header_size = (0usize, 0usize);
+
+        for line in text.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("#N") {
+                pattern.name = Some(name.trim().to_string()).filter(|n| !n.is_empty());
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('x') && body.is_empty() {
+                // en-tête : x = 3, y = 3, rule = B3/S23
+                for field in line.split(',') {
+                    let Some((key, value)) = field.split_once('=') else {
+                        continue;
+                    };
+                    match key.trim() {
+                        "x" => header_size.0 = value.trim().parse().unwrap_or(0),
+                        "y" => header_size.1 = value.trim().parse().unwrap_or(0),
+                        "rule" => {
+                            let rule = value.trim().parse::<Rule>();
+                            pattern.rule = Some(rule.map_err(|e| PatternError::Parse(e.to_string()))?);
+                        }
+                        _ => {}
+                    }
+                }
+                if header_size.0 > MAX_SIZE || header_size.1 > MAX_SIZE {
+                    return Err(PatternError::Parse(format!("plus de {MAX_SIZE} cellules de côté")));
+                }
+                continue;
+            }
+            body.push_str(line);
+        }
+
+        let (mut x, mut y) = (0usize, 0usize);
+        let mut count = String::new();
+        for c in body.chars() {
+            if c.is_ascii_digit() {
+                count.push(c);
+                continue;
+            }
+            let n = if count.is_empty() {
+                1
+            } else {
+                count.parse::<usize>().map_err(|e| PatternError::Parse(e.to_string()))?
+            };
+            count.clear();
+            // `n` vient du fichier : il ne doit ni déborder ni faire allouer sans fin
+            let too_large = || PatternError::Parse(format!("plus de {MAX_SIZE} cellules de côté"));
+
+            let mutation = match c {
+                'b' | '.' => None,
+                'o' | 'A' => Some(MutationType::None),
+                'B' => Some(MutationType::Blue),
+                'C' => Some(MutationType::Red),
+                '$' => {
+                    if n > MAX_SIZE - y {
+                        return Err(too_large());
+                    }
+                    y += n;
+                    x = 0;
+                    continue;
+                }
+                '!' => break,
+                c if c.is_whitespace() => continue,
+                other => return Err(PatternError::Parse(format!("caractère inattendu « {other} »"))),
+            };
+            if n > MAX_SIZE - x || y >= MAX_SIZE {
+                return Err(too_large());
+            }
+            if let Some(mutation) = mutation {
+                if pattern.cells.len() + n > MAX_CELLS {
+                    return Err(PatternError::Parse(format!("plus de {MAX_CELLS} cellules vivantes")));
+                }
+                pattern.cells.extend((x..x + n).map(|cx| (cx, y, mutation)));
+            }
+            x += n;
+        }
+
+        // l’en-tête peut déclarer des marges vides autour des cellules
+        pattern.fit_bounds();
+        pattern.width = pattern.width.max(header_size.0);
+        pattern.height = pattern.height.max(header_size.1);
+        Ok(pattern)
+    }
+
+    pub fn parse_plaintext(text: &str) -> Result<Self, PatternError> {
+        let mut pattern = Pattern {
+            name: text.lines().find_map(|l| l.strip_prefix("!Name:")).map(|n| n.trim().to_string()),
+            ..Default::default()
+        };
+        let rows = text.lines().filter(|l| !l.starts_with('!'));
+        for (y, row) in rows.enumerate() {
+            for (x, c) in row.trim_end().chars().enumerate() {
+                match c {
+                    'O' | 'o' | '*' => pattern.cells.push((x, y, MutationType::None)),
+                    '.' => {}
+                    other => return Err(PatternError::Parse(format!("caractère inattendu « {other} »"))),
+                }
+            }
+        }
+        pattern.fit_bounds();
+        Ok(pattern)
+    }
+
+    /// Format macrocell : arbre de nœuds, feuilles 8×8 (`.`, `*`, `$`) ou nœuds de niveau 1
+    /// à 4 états (0 mort, 1 normal, 2 bleu, 3 rouge) ; le dernier nœud est la racine
+    pub fn parse_macrocell(text: &str) -> Result<Self, PatternError> {
+        let mut pattern = Pattern::default();
+        let mut nodes: Vec<MacroNode> = Vec::new();
+
+        for line in text.lines().map(str::trim) {
+            if let Some(rule) = line.strip_prefix("#R") {
+                let rule = rule.trim().parse::<Rule>();
+                pattern.rule = Some(rule.map_err(|e| PatternError::Parse(e.to_string()))?);
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("#N") {
+                pattern.name = Some(name.trim().to_string()).filter(|n| !n.is_empty());
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+
+            if line.starts_with(['.', '*', '$']) {
+                let mut leaf = Vec::new();
+                let (mut x, mut y) = (0u64, 0u64);
+                for c in line.chars() {
+                    match c {
+                        '.' => x += 1,
+                        '*' => {
+                            leaf.push((x, y, MutationType::None));
+                            x += 1;
+                        }
+                        '$' => {
+                            x = 0;
+                            y += 1;
+                        }
+                        other => return Err(PatternError::Parse(format!("caractère inattendu « {other} »"))),
+                    }
+                }
+                nodes.push(MacroNode::Leaf(leaf));
+                continue;
+            }
+
+            // "niveau nw ne sw se" : indices des nœuds précédents (à partir de 1), 0 = vide
+            let numbers: Vec<usize> = line
+                .split_whitespace()
+                .map(|n| n.parse::<usize>())
+                .collect::<Result<_, _>>()
+                .map_err(|e| PatternError::Parse(e.to_string()))?;
+            let [level, nw, ne, sw, se] = numbers[..] else {
+                return Err(PatternError::Parse(format!("nœud invalide « {line} »")));
+            };
+            let children = [nw, ne, sw, se];
+            if level == 1 {
+                nodes.push(MacroNode::States(children));
+            } else if level > 1 && level <= 62 && children.iter().all(|&c| c <= nodes.len()) {
+                nodes.push(MacroNode::Inner(level as u32, children));
+            } else {
+                return Err(PatternError::Parse(format!("nœud invalide « {line} »")));
+            }
+        }
+
+        // parcours de l’arbre depuis la racine
+        let mut cells: Vec<(u64, u64, MutationType)> = Vec::new();
+        let mut stack = match nodes.len() {
+            0 => Vec::new(),
+            n => vec![(n, 0u64, 0u64)],
+        };
+        while let Some((index, x, y)) = stack.pop() {
+            match &nodes[index - 1] {
+                MacroNode::Leaf(leaf) => cells.extend(leaf.iter().map(|&(cx, cy, m)| (x + cx, y + cy, m))),
+                MacroNode::States(states) => {
+                    let offsets = [(0, 0), (1, 0), (0, 1), (1, 1)];
+                    for (&state, (dx, dy)) in states.iter().zip(offsets) {
+                        let mutation = match state {
+                            0 => continue,
+                            2 => MutationType::Blue,
+                            3 => MutationType::Red,
+                            _ => MutationType::None,
+                        };
+                        cells.push((x + dx, y + dy, mutation));
+                    }
+                }
+                MacroNode::Inner(level, children) => {
+                    let half = 1u64 << (level - 1);
+                    let offsets = [(0, 0), (half, 0), (0, half), (half, half)];
+                    for (&child, (dx, dy)) in children.iter().zip(offsets) {
+                        if child != 0 {
+                            stack.push((child, x + dx, y + dy));
+                        }
+                    }
+                }
+            }
+            if cells.len() > MAX_CELLS {
+                return Err(PatternError::Parse(format!("plus de {MAX_CELLS} cellules vivantes")));
+            }
+        }
+
+        // coin haut-gauche ramené en (0, 0)
+        let min_x = cells.iter().map(|c| c.0).min().unwrap_or(0);
+        let min_y = cells.iter().map(|c| c.1).min().unwrap_or(0);
+        pattern.cells = cells
+            .into_iter()
+            .map(|(x, y, m)| ((x - min_x) as usize, (y - min_y) as usize, m))
+            .collect();
+        pattern.fit_bounds();
+        Ok(pattern)
+    }
+
+    /// Rotation de 90° dans le sens horaire
+    pub fn rotate_clockwise(&self) -> Pattern {
+        Pattern {
+            width: self.height,
+            height: self.width,
+            cells: self.cells.iter().map(|&(x, y, m)| (self.height - 1 - y, x, m)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Miroir gauche-droite
+    pub fn flip_horizontal(&self) -> Pattern {
+        Pattern {
+            cells: self.cells.iter().map(|&(x, y, m)| (self.width - 1 - x, y, m)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Miroir haut-bas
+    pub fn flip_vertical(&self) -> Pattern {
+        Pattern {
+            cells: self.cells.iter().map(|&(x, y, m)| (x, self.height - 1 - y, m)).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Export RLE : `b`/`o` pour un motif sans mutation, sinon états étendus `.`/`A`/`B`/`C`
+    pub fn to_rle(&self) -> String {
+        let extended = self.cells.iter().any(|c| c.2 != MutationType::None);
+        let symbol = |state: Option<MutationType>| match (state, extended) {
+            (None, false) => 'b',
+            (None, true) => '.',
+            (Some(_), false) => 'o',
+            (Some(MutationType::None), true) => 'A',
+            (Some(MutationType::Blue), true) => 'B',
+            (Some(MutationType::Red), true) => 'C',
+        };
+
+        let mut rows = vec![vec![None; self.width]; self.height];
+        for &(x, y, m) in &self.cells {
+            rows[y][x] = Some(m);
+        }
+
+        // suite de (nombre, symbole) ; les morts en fin de ligne et les lignes vides finales sont omis
+        let mut runs: Vec<(usize, char)> = Vec::new();
+        let mut push = |n: usize, c: char| match runs.last_mut() {
+            Some((count, last)) if *last == c => *count += n,
+            _ => runs.push((n, c)),
+        };
+        let last_row = rows.iter().rposition(|r| r.iter().any(Option::is_some));
+        for (y, row) in rows.iter().enumerate().take(last_row.map_or(0, |r| r + 1)) {
+            if y > 0 {
+                push(1, '$');
+            }
+            let len = row.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+            for &state in &row[..len] {
+                push(1, symbol(state));
+            }
+        }
+        push(1, '!');
+
+        let mut text = String::new();
+        if let Some(name) = &self.name {
+            text.push_str(&format!("#N {name}\n"));
+        }
+        text.push_str(&format!("x = {}, y = {}", self.width, self.height));
+        if let Some(rule) = self.rule {
+            text.push_str(&format!(", rule = {rule}"));
+        }
+        text.push('\n');
+
+        // lignes de 70 caractères au plus, comme les autres outils
+        let mut line = String::new();
+        for (n, c) in runs {
+            let token = if n > 1 { format!("{n}{c}") } else { c.to_string() };
+            if line.len() + token.len() > 70 {
+                text.push_str(&line);
+                text.push('\n');
+                line.clear();
+            }
+            line.push_str(&token);
+        }
+        text.push_str(&line);
+        text.push('\n');
+        text
+    }
+
+    // Dimensions = boîte englobante des cellules vivantes
+    fn fit_bounds(&mut self) {
+        self.width = self.cells.iter().map(|c| c.0 + 1).max().unwrap_or(0);
+        self.height = self.cells.iter().map(|c| c.1 + 1).max().unwrap_or(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(pattern: &Pattern) -> Vec<(usize, usize, MutationType)> {
+        let mut cells = pattern.cells.clone();
+        cells.sort_by_key(|&(x, y, _)| (y, x));
+        cells
+    }
+
+    fn glider() -> Pattern {
+        Pattern::parse_rle("x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!").unwrap()
+    }
+
+    #[test]
+    fn rle_round_trip() {
+        let glider = glider();
+        let parsed = Pattern::parse_rle(&glider.to_rle()).unwrap();
+        assert_eq!(sorted(&parsed), sorted(&glider));
+        assert_eq!((parsed.width, parsed.height, parsed.rule), (3, 3, glider.rule));
+    }
+
+    #[test]
+    fn rle_round_trip_keeps_strains_and_margins() {
+        let pattern = Pattern {
+            width: 80,
+            height: 5,
+            cells: vec![(0, 0, MutationType::Blue), (79, 1, MutationType::Red), (3, 3, MutationType::None)],
+            ..Default::default()
+        };
+        let rle = pattern.to_rle();
+        assert!(rle.lines().all(|line| line.len() <= 70));
+        let parsed = Pattern::parse_rle(&rle).unwrap();
+        assert_eq!(sorted(&parsed), sorted(&pattern));
+        assert_eq!((parsed.width, parsed.height), (80, 5));
+    }
+
+    #[test]
+    fn transforms() {
+        let glider = glider();
+        let rotated = glider.rotate_clockwise();
+        assert_eq!((rotated.width, rotated.height), (3, 3));
+        let four = rotated.rotate_clockwise().rotate_clockwise().rotate_clockwise();
+        assert_eq!(sorted(&four), sorted(&glider));
+        assert_eq!(sorted(&glider.flip_horizontal().flip_horizontal()), sorted(&glider));
+        assert_eq!(sorted(&glider.flip_vertical().flip_vertical()), sorted(&glider));
+
+        let bar = Pattern { width: 3, height: 1, cells: vec![(0, 0, MutationType::None)], ..Default::default() };
+        let rotated = bar.rotate_clockwise();
+        assert_eq!((rotated.width, rotated.height), (1, 3));
+        assert_eq!(rotated.cells, vec![(0, 0, MutationType::None)]);
+    }
+
+    #[test]
+    fn parses_macrocell() {
+        // feuille 8×8 contenant le planeur, placée au sud-est d’un nœud de niveau 4 (16×16)
+        let text = "[M2] (golly)\n#R B3/S23\n.*$..*$***$\n4 0 0 0 1\n";
+        let pattern = Pattern::parse_macrocell(text).unwrap();
+        assert_eq!(pattern.rule, Some(Rule::CONWAY));
+        assert_eq!(sorted(&pattern), sorted(&glider()));
+
+        // nœuds de niveau 1 à 4 états : normale, bleue, rouge
+        let pattern = Pattern::parse_macrocell("1 1 2 0 3\n").unwrap();
+        let strains = [(0, 0, MutationType::None), (1, 0, MutationType::Blue), (1, 1, MutationType::Red)];
+        assert_eq!(sorted(&pattern), strains);
+        assert!(Pattern::parse_macrocell("4 1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn parses_rle_header_and_body() {
+        let glider = Pattern::parse_rle("#N Glider\n#C commentaire\nx = 3, y = 3, rule = B3/S23\nbob$2bo$\n3o!").unwrap();
+        assert_eq!((glider.width, glider.height), (3, 3));
+        assert_eq!(glider.rule, Some(Rule::CONWAY));
+        assert_eq!(glider.name.as_deref(), Some("Glider"));
+        let expected = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(|(x, y)| (x, y, MutationType::None));
+        assert_eq!(sorted(&glider), expected);
+    }
+
+    #[test]
+    fn rle_rejects_oversized_input() {
+        assert!(matches!(Pattern::parse_rle("x = 100001, y = 1\no!"), Err(PatternError::Parse(_))));
+        assert!(matches!(Pattern::parse_rle("x = 1, y = 1\n99999999999999999999o!"), Err(PatternError::Parse(_))));
+        assert!(matches!(Pattern::parse_rle(&format!("{}o!", usize::MAX)), Err(PatternError::Parse(_))));
+        assert!(matches!(Pattern::parse_rle(&format!("{}$o!", usize::MAX)), Err(PatternError::Parse(_))));
+        assert!(matches!(Pattern::parse_rle("60000o40001o!"), Err(PatternError::Parse(_))));
+        assert!(matches!(Pattern::parse_rle("100000$o!"), Err(PatternError::Parse(_))));
+        // 20 lignes de 60 000 cellules : chaque côté reste sous `MAX_SIZE`, pas le total sous `MAX_CELLS`
+        assert!(matches!(
+            Pattern::parse_rle(&"60000o$".repeat(20)),
+            Err(PatternError::Parse(e)) if e.contains("vivantes")
+        ));
+        // une ligne pleine tout en bas reste permise
+        let edge = Pattern::parse_rle("99999$100000o!").unwrap();
+        assert_eq!((edge.width, edge.height), (MAX_SIZE, MAX_SIZE));
+        assert!(matches!(Pattern::parse_rle("3o?"), Err(PatternError::Parse(_))));
+    }
+
+    #[test]
+    fn parses_plaintext() {
+        let pattern = Pattern::parse_plaintext("!Name: Glider\n.O\n..O\nOOO\n").unwrap();
+        assert_eq!(pattern.name.as_deref(), Some("Glider"));
+        assert_eq!(sorted(&pattern), sorted(&glider()));
+        assert!(Pattern::parse_plaintext("O#").is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use crate::{
+    census::Census,
+    mutation_setting::MutationSettings,
+    stats::{GenerationStats, PopulationHistory}
+};
+
+const CSV_HEADER: &str =
+    "generation,total,normal,blue,red,births,deaths,min_x,min_y,max_x,max_y,allow_blue,allow_red,blue_probability,red_probability";
+
+const CENSUS_HEADER: &str = "generation,object,count";
+
+/// Enregistrement des statistiques par génération dans un fichier CSV
+///
+/// Les recensements d’objets vont dans un second fichier, `<nom>_census.csv`.
+#[derive(Resource, Default)]
+pub struct StatsRecorder {
+    writer: Option<BufWriter<File>>,
+    census_writer: Option<BufWriter<File>>,
+}
+
+impl StatsRecorder {
+    /// Ouvre (ou écrase) le fichier et écrit l’en-tête
+    pub fn start(&mut self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{CSV_HEADER}")?;
+
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let mut census_writer = BufWriter::new(File::create(path.with_file_name(format!("{stem}_census.csv")))?);
+        writeln!(census_writer, "{CENSUS_HEADER}")?;
+
+        self.writer = Some(writer);
+        self.census_writer = Some(census_writer);
+        info!("Enregistrement des statistiques dans {}", path.display());
+        Ok(())
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(mut writer) = self.writer.take()
+            && let Err(e) = writer.flush()
+        {
+            error!("Impossible de finaliser l’enregistrement : {e}");
+        }
+        if let Some(mut writer) = self.census_writer.take()
+            && let Err(e) = writer.flush()
+        {
+            error!("Impossible de finaliser le recensement : {e}");
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    /// Ajoute une ligne pour la génération donnée
+    pub fn write(&mut self, stats: &GenerationStats, settings: &MutationSettings) -> io::Result<()> {
+        let Some(writer) = self.writer.as_mut() else {
+            return Ok(());
+        };
+        let bounds = match stats.bounds {
+            Some((min_x, min_y, max_x, max_y)) => format!("{min_x},{min_y},{max_x},{max_y}"),
+            None => ",,,".to_string(),
+        };
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            stats.generation,
+            stats.total,
+            stats.normal,
+            stats.blue,
+            stats.red,
+            stats.births,
+            stats.deaths,
+            bounds,
+            settings.allow_blue,
+            settings.allow_red,
+            settings.blue_probability,
+            settings.red_probability,
+        )?;
+        // on vide le tampon à chaque ligne : le fichier reste lisible pendant l’enregistrement
+        writer.flush()
+    }
+
+    /// Ajoute une ligne par objet recensé
+    pub fn write_census(&mut self, census: &Census) -> io::Result<()> {
+        let Some(writer) = self.census_writer.as_mut() else {
+            return Ok(());
+        };
+        for (name, count) in &census.objects {
+            writeln!(writer, "{},{},{}", census.generation, name, count)?;
+        }
+        writer.flush()
+    }
+}
+
+/// Nom de fichier par défaut quand l’enregistrement est lancé depuis l’interface
+pub fn default_record_path() -> PathBuf {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    PathBuf::from(format!("stats_{secs}.csv"))
+}
+
+// Écrit chaque nouvelle génération enregistrée dans l’historique
+pub fn record_stats_system(
+    history: Res<PopulationHistory>,
+    settings: Res<MutationSettings>,
+    mut recorder: ResMut<StatsRecorder>,
+) {
+    if !history.is_changed() || !recorder.is_recording() {
+        return;
+    }
+    if let Some(stats) = history.latest()
+        && let Err(e) = recorder.write(stats, &settings)
+    {
+        error!("Erreur d’écriture CSV : {e}");
+        recorder.stop();
+    }
+}
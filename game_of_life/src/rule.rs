@@ -0,0 +1,136 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Règle de type "B3/S23" : bit n = n voisines vivantes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rule {
+    pub birth: u16,
+    pub survival: u16,
+}
+
+impl Rule {
+    /// Règle classique de Conway, B3/S23
+    pub const CONWAY: Rule = Rule { birth: 1 << 3, survival: (1 << 2) | (1 << 3) };
+
+    pub fn next_state(&self, alive: bool, alive_neighbors: usize) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+        alive_neighbors <= 8 && mask & (1 << alive_neighbors) != 0
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::CONWAY
+    }
+}
+
+#[derive(Debug)]
+pub struct RuleParseError(String);
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "règle invalide « {} » (attendu par exemple B3/S23)", self.0)
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+// Liste de chiffres 0..=8 -> masque
+fn parse_counts(digits: &str, original: &str) -> Result<u16, RuleParseError> {
+    digits.chars().try_fold(0u16, |mask, c| match c.to_digit(10) {
+        Some(n) if n <= 8 => Ok(mask | (1 << n)),
+        _ => Err(RuleParseError(original.to_string())),
+    })
+}
+
+/// Accepte la notation B/S ("B36/S23") et l’ancienne notation S/B ("23/36")
+impl FromStr for Rule {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| RuleParseError(s.to_string()))?;
+        let (left, right) = (left.to_ascii_uppercase(), right.to_ascii_uppercase());
+
+        let (birth, survival) = match (left.strip_prefix('B'), right.strip_prefix('S')) {
+            (Some(b), Some(s)) => (b.to_string(), s.to_string()),
+            // "S23/B3"
+            _ => match (left.strip_prefix('S'), right.strip_prefix('B')) {
+                (Some(s), Some(b)) => (b.to_string(), s.to_string()),
+                // notation historique "survie/naissance"
+                _ => (right, left),
+            },
+        };
+
+        Ok(Rule {
+            birth: parse_counts(&birth, s)?,
+            survival: parse_counts(&survival, s)?,
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = |mask: u16| -> String {
+            (0..=8).filter(|n| mask & (1 << n) != 0).map(|n| n.to_string()).collect()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))
+    }
+}
+
+// Sérialisée sous sa forme texte "B3/S23"
+impl Serialize for Rule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bs_sb_and_legacy_notations() {
+        let highlife = Rule { birth: (1 << 3) | (1 << 6), survival: (1 << 2) | (1 << 3) };
+        assert_eq!("B36/S23".parse::<Rule>().unwrap(), highlife);
+        assert_eq!("b36/s23".parse::<Rule>().unwrap(), highlife);
+        assert_eq!("S23/B36".parse::<Rule>().unwrap(), highlife);
+        assert_eq!("23/36".parse::<Rule>().unwrap(), highlife);
+        assert_eq!(" B3/S23 ".parse::<Rule>().unwrap(), Rule::CONWAY);
+        // sans naissance ni survie
+        assert_eq!("B/S".parse::<Rule>().unwrap(), Rule { birth: 0, survival: 0 });
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        for rule in ["", "B3S23", "B9/S23", "B3/S2x", "B3/S23/C2"] {
+            assert!(rule.parse::<Rule>().is_err(), "{rule}");
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for rule in ["B3/S23", "B36/S23", "B0/S012345678", "B/S"] {
+            assert_eq!(rule.parse::<Rule>().unwrap().to_string(), rule);
+        }
+    }
+
+    #[test]
+    fn next_state_follows_masks() {
+        let conway = Rule::CONWAY;
+        assert!(conway.next_state(false, 3));
+        assert!(!conway.next_state(false, 2));
+        assert!(conway.next_state(true, 2));
+        assert!(!conway.next_state(true, 4));
+        assert!(!conway.next_state(true, 9));
+    }
+}
@@ -0,0 +1,575 @@
+use bevy::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
+use rhai::{Dynamic, Engine, EvalAltResult, FnPtr, Map, NativeCallContext, ParseError, Position, Scope, AST, FLOAT, INT};
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use crate::{
+    cell::{Cell, MutationType},
+    detection::CycleDetector,
+    edits::EditHistory,
+    grid::{Grid, GridConfig},
+    mutation_setting::MutationSettings,
+    pattern::Pattern,
+    rule::Rule,
+    stats::{GenerationStats, PopulationHistory, HISTORY_LEN},
+    systems::{apply_grid, SimRng},
+    toast::Toast,
+    locale::Localization
+};
+
+/// Script à exécuter dans l’application : fichier (`--script`) ou texte
+#[derive(Event, Clone, Debug)]
+pub enum ScriptEvent {
+    RunFile(PathBuf),
+    Run(String),
+}
+
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(io::Error),
+    Parse(ParseError),
+    Eval(Box<EvalAltResult>),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptError::Io(e) => write!(f, "lecture impossible : {e}"),
+            ScriptError::Parse(e) => write!(f, "script invalide : {e}"),
+            ScriptError::Eval(e) => match &**e {
+                EvalAltResult::ErrorTerminated(reason, _) => write!(f, "script interrompu : {reason}"),
+                e => write!(f, "{e}"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<io::Error> for ScriptError {
+    fn from(e: io::Error) -> Self {
+        ScriptError::Io(e)
+    }
+}
+
+impl From<ParseError> for ScriptError {
+    fn from(e: ParseError) -> Self {
+        ScriptError::Parse(e)
+    }
+}
+
+impl From<Box<EvalAltResult>> for ScriptError {
+    fn from(e: Box<EvalAltResult>) -> Self {
+        ScriptError::Eval(e)
+    }
+}
+
+/// Durée maximale d’un script ou d’un rappel dans l’application, qu’il ne bloque pas indéfiniment
+pub const TIME_LIMIT: Duration = Duration::from_secs(2);
+/// Générations au plus par appel de `step(n)`
+pub const MAX_STEPS: INT = 10_000;
+/// Taille maximale d’une chaîne, d’un tableau ou d’une table créés par un script, qu’il n’épuise pas la mémoire
+const MAX_STRING: usize = 1 << 20;
+const MAX_ARRAY: usize = 1 << 20;
+const MAX_MAP: usize = 1 << 16;
+
+/// Ce que le script voit et modifie
+pub struct ScriptWorld {
+    pub grid: Grid,
+    pub settings: MutationSettings,
+    pub rng: StdRng,
+    /// Statistiques des dernières générations calculées par `step`, dans l’ordre
+    pub steps: VecDeque<GenerationStats>,
+    /// Lignes affichées par `print`
+    pub output: Vec<String>,
+    callbacks: Vec<FnPtr>,
+    /// Un rappel en cours ne déclenche pas les rappels de ses propres `step`
+    in_callback: bool,
+    /// Fin du temps accordé au script en cours
+    deadline: Option<Instant>,
+}
+
+impl ScriptWorld {
+    pub fn new(grid: Grid, settings: MutationSettings, rng: StdRng) -> Self {
+        Self {
+            grid,
+            settings,
+            rng,
+            steps: VecDeque::new(),
+            output: Vec::new(),
+            callbacks: Vec::new(),
+            in_callback: false,
+            deadline: None,
+        }
+    }
+
+    /// Statistiques de la grille, avec naissances et morts de la dernière génération calculée
+    fn stats(&self) -> GenerationStats {
+        let mut stats = self.grid.stats();
+        if let Some(last) = self.steps.back().filter(|s| s.generation == stats.generation) {
+            stats.births = last.births;
+            stats.deaths = last.deaths;
+        }
+        stats
+    }
+
+    /// Erreur si le script en cours a dépassé son temps
+    fn check_deadline(&self) -> ScriptResult<()> {
+        match self.deadline {
+            Some(deadline) if Instant::now() > deadline => Err(terminated()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn terminated() -> Box<EvalAltResult> {
+    EvalAltResult::ErrorTerminated("temps maximal dépassé".into(), Position::NONE).into()
+}
+
+/// Moteur Rhai des scripts lancés par `--script`, la console ou le mode sans fenêtre ;
+/// garde les variables et fonctions des scripts précédents
+///
+/// Fonctions disponibles :
+/// - `width()`, `height()`, `generation()`
+/// - `get(x, y)`, `strain(x, y)` (`"normal"`, `"blue"`, `"red"`, ou `""` si la cellule est morte)
+/// - `set(x, y, vivante)`, `set(x, y, vivante, souche)`, `clear()`, `randomize(densité)`
+/// - `step()`, `step(n)` (au plus `MAX_STEPS` générations par appel)
+/// - `load(fichier)` au centre, `load(fichier, x, y)` coin haut-gauche en (x, y), `save(fichier)` en RLE
+/// - `rule()`, `set_rule("B36/S23")`
+/// - `mutations()`, `set_mutations(#{ blue: false, red_prob: 0.2 })` (clés `blue`, `red`, `blue_prob`, `red_prob`)
+/// - `stats()` : `generation`, `total`, `normal`, `blue`, `red`, `births`, `deaths`
+/// - `on_generation(|stats| ...)` : appelée après chaque génération, celles du script comme celles de l’application
+#[derive(Resource)]
+pub struct ScriptHost {
+    engine: Engine,
+    scope: Scope<'static>,
+    /// Fonctions déjà définies, pour les scripts suivants et les rappels
+    library: AST,
+    world: Arc<Mutex<ScriptWorld>>,
+    /// Dernière génération transmise aux rappels
+    notified: Option<u64>,
+    /// Temps accordé à chaque script et rappel, `None` sans limite
+    time_limit: Option<Duration>,
+}
+
+// grille, réglages et générateur remplacés par ceux de l’application avant chaque script
+impl Default for ScriptHost {
+    fn default() -> Self {
+        let world = ScriptWorld::new(Grid::empty(&GridConfig::default()), MutationSettings::default(), StdRng::from_entropy());
+        Self::new(world)
+    }
+}
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+impl ScriptHost {
+    pub fn new(world: ScriptWorld) -> Self {
+        let world = Arc::new(Mutex::new(world));
+        let mut engine = Engine::new();
+        engine.set_max_string_size(MAX_STRING).set_max_array_size(MAX_ARRAY).set_max_map_size(MAX_MAP);
+        register_api(&mut engine, &world);
+        let output = world.clone();
+        engine.on_print(move |line| lock(&output).output.push(line.to_string()));
+        // `loop {}` ou `while true {}` s’arrêtent une fois le temps écoulé
+        let progress = world.clone();
+        engine.on_progress(move |operations| {
+            (operations % 1024 == 0 && lock(&progress).check_deadline().is_err()).then(|| "temps maximal dépassé".into())
+        });
+        Self { engine, scope: Scope::new(), library: AST::empty(), world, notified: None, time_limit: Some(TIME_LIMIT) }
+    }
+
+    /// Temps accordé à chaque script et rappel (`None` : sans limite, pour le mode sans fenêtre)
+    pub fn set_time_limit(&mut self, limit: Option<Duration>) {
+        self.time_limit = limit;
+    }
+
+    /// `print` écrit ailleurs que dans `ScriptWorld::output` (sortie standard du mode sans fenêtre)
+    pub fn on_print(&mut self, callback: impl Fn(&str) + Send + Sync + 'static) {
+        self.engine.on_print(callback);
+    }
+
+    pub fn world(&self) -> MutexGuard<'_, ScriptWorld> {
+        lock(&self.world)
+    }
+
+    pub fn has_callbacks(&self) -> bool {
+        !self.world().callbacks.is_empty()
+    }
+
+    /// Exécute un script ; ses variables et fonctions restent disponibles pour les suivants
+    pub fn run(&mut self, source: &str) -> Result<(), ScriptError> {
+        let ast = self.engine.compile(source)?;
+        self.library += ast.clone_functions_only();
+        let program = self.library.merge(&ast);
+        self.start_clock();
+        let result = self.engine.run_ast_with_scope(&mut self.scope, &program);
+        self.world().deadline = None;
+        Ok(result?)
+    }
+
+    pub fn run_file(&mut self, path: &Path) -> Result<(), ScriptError> {
+        let source = fs::read_to_string(path)?;
+        self.run(&source)
+    }
+
+    /// Appelle les rappels `on_generation` pour une génération calculée hors du script
+    pub fn notify_generation(&mut self, stats: GenerationStats) -> Result<(), ScriptError> {
+        let callbacks = self.world().callbacks.clone();
+        self.start_clock();
+        self.world().in_callback = true;
+        let result = callbacks
+            .iter()
+            .try_for_each(|callback| callback.call::<Dynamic>(&self.engine, &self.library, (stats_map(&stats),)).map(drop));
+        let mut world = self.world();
+        world.in_callback = false;
+        world.deadline = None;
+        Ok(result?)
+    }
+
+    fn start_clock(&mut self) {
+        let deadline = self.time_limit.map(|limit| Instant::now() + limit);
+        self.world().deadline = deadline;
+    }
+
+    pub fn clear_callbacks(&mut self) {
+        self.world().callbacks.clear();
+    }
+}
+
+// un script qui a paniqué au milieu d’une fonction laisse un monde encore utilisable
+fn lock(world: &Mutex<ScriptWorld>) -> MutexGuard<'_, ScriptWorld> {
+    world.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn stats_map(stats: &GenerationStats) -> Map {
+    let mut map = Map::new();
+    map.insert("generation".into(), (stats.generation as INT).into());
+    map.insert("total".into(), (stats.total as INT).into());
+    map.insert("normal".into(), (stats.normal as INT).into());
+    map.insert("blue".into(), (stats.blue as INT).into());
+    map.insert("red".into(), (stats.red as INT).into());
+    map.insert("births".into(), (stats.births as INT).into());
+    map.insert("deaths".into(), (stats.deaths as INT).into());
+    map
+}
+
+fn strain_name(mutation: MutationType) -> &'static str {
+    match mutation {
+        MutationType::None => "normal",
+        MutationType::Blue => "blue",
+        MutationType::Red => "red",
+    }
+}
+
+fn parse_strain(name: &str) -> ScriptResult<MutationType> {
+    match name {
+        "normal" => Ok(MutationType::None),
+        "blue" => Ok(MutationType::Blue),
+        "red" => Ok(MutationType::Red),
+        _ => Err(format!("souche inconnue « {name} » (normal, blue ou red)").into()),
+    }
+}
+
+fn number(value: &Dynamic, key: &str) -> ScriptResult<f32> {
+    value
+        .as_float()
+        .or_else(|_| value.as_int().map(|n| n as FLOAT))
+        .map(|n| n as f32)
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| format!("« {key} » attend un nombre fini").into())
+}
+
+// 0.1 reste 0.1 dans le script, et non 0.10000000149011612
+fn probability(p: f32) -> FLOAT {
+    p.to_string().parse().unwrap_or(p as FLOAT)
+}
+
+fn flag(value: &Dynamic, key: &str) -> ScriptResult<bool> {
+    value.as_bool().map_err(|_| format!("« {key} » attend true ou false").into())
+}
+
+/// Cellule (x, y) de la grille, erreur si elle en sort
+fn position(grid: &Grid, x: INT, y: INT) -> ScriptResult<usize> {
+    if x < 0 || y < 0 || x as usize >= grid.width || y as usize >= grid.height {
+        return Err(format!("({x}, {y}) hors de la grille {}×{}", grid.width, grid.height).into());
+    }
+    Ok(grid.index(x as usize, y as usize))
+}
+
+fn set_cell(world: &Mutex<ScriptWorld>, x: INT, y: INT, alive: bool, mutation: MutationType) -> ScriptResult<()> {
+    let mut world = lock(world);
+    let index = position(&world.grid, x, y)?;
+    let cell = &mut world.grid.cells[index];
+    if cell.alive != alive || (alive && cell.mutation != mutation) {
+        *cell = Cell { alive, mutation: if alive { mutation } else { MutationType::None }, ..Cell::dead(cell.x, cell.y) };
+    }
+    Ok(())
+}
+
+fn load_pattern(world: &Mutex<ScriptWorld>, path: &str, at: Option<(INT, INT)>) -> ScriptResult<()> {
+    let pattern = Pattern::load(Path::new(path)).map_err(|e| format!("{path} : {e}"))?;
+    let mut world = lock(world);
+    let (x, y) = at.unwrap_or((
+        (world.grid.width as INT - pattern.width as INT) / 2,
+        (world.grid.height as INT - pattern.height as INT) / 2,
+    ));
+    world.grid.stamp(&pattern, x, y);
+    Ok(())
+}
+
+/// `n` générations ; les rappels sont appelés après chacune, le monde déverrouillé
+fn step(context: &NativeCallContext, world: &Mutex<ScriptWorld>, n: INT) -> ScriptResult<()> {
+    if n > MAX_STEPS {
+        return Err(format!("step({n}) : au plus {MAX_STEPS} générations par appel").into());
+    }
+    for _ in 0..n.max(0) {
+        let (stats, callbacks) = {
+            let mut guard = lock(world);
+            let world = &mut *guard;
+            world.check_deadline()?;
+            let stats = world.grid.step(&world.settings, &mut world.rng);
+            if world.steps.len() == HISTORY_LEN {
+                world.steps.pop_front();
+            }
+            world.steps.push_back(stats);
+            let callbacks = if world.in_callback { Vec::new() } else { world.callbacks.clone() };
+            (stats, callbacks)
+        };
+        if callbacks.is_empty() {
+            continue;
+        }
+        lock(world).in_callback = true;
+        let result = callbacks
+            .iter()
+            .try_for_each(|callback| callback.call_within_context::<Dynamic>(context, (stats_map(&stats),)).map(drop));
+        lock(world).in_callback = false;
+        result?;
+    }
+    Ok(())
+}
+
+fn register_api(engine: &mut Engine, world: &Arc<Mutex<ScriptWorld>>) {
+    let w = world.clone();
+    engine.register_fn("width", move || lock(&w).grid.width as INT);
+    let w = world.clone();
+    engine.register_fn("height", move || lock(&w).grid.height as INT);
+    let w = world.clone();
+    engine.register_fn("generation", move || lock(&w).grid.generation as INT);
+
+    // 🔲 Cellules
+    let w = world.clone();
+    engine.register_fn("get", move |x: INT, y: INT| -> bool {
+        let world = lock(&w);
+        position(&world.grid, x, y).is_ok_and(|i| world.grid.cells[i].alive)
+    });
+    let w = world.clone();
+    engine.register_fn("strain", move |x: INT, y: INT| -> String {
+        let world = lock(&w);
+        match position(&world.grid, x, y).map(|i| world.grid.cells[i]) {
+            Ok(cell) if cell.alive => strain_name(cell.mutation).to_string(),
+            _ => String::new(),
+        }
+    });
+    let w = world.clone();
+    engine.register_fn("set", move |x: INT, y: INT, alive: bool| set_cell(&w, x, y, alive, MutationType::None));
+    let w = world.clone();
+    engine.register_fn("set", move |x: INT, y: INT, alive: bool, strain: &str| {
+        set_cell(&w, x, y, alive, parse_strain(strain)?)
+    });
+    let w = world.clone();
+    engine.register_fn("clear", move || {
+        let mut world = lock(&w);
+        for cell in &mut world.grid.cells {
+            *cell = Cell::dead(cell.x, cell.y);
+        }
+    });
+    let w = world.clone();
+    engine.register_fn("randomize", move |density: FLOAT| -> ScriptResult<()> {
+        if !density.is_finite() {
+            return Err("randomize attend une densité finie".into());
+        }
+        let mut guard = lock(&w);
+        let world = &mut *guard;
+        let config = GridConfig { width: world.grid.width, height: world.grid.height, boundary: world.grid.boundary, rule: world.grid.rule };
+        let mut grid = Grid::random(&config, density.clamp(0.0, 1.0), &world.settings, &mut world.rng);
+        grid.generation = world.grid.generation;
+        world.grid = grid;
+        Ok(())
+    });
+
+    // ⏭️ Générations
+    let w = world.clone();
+    engine.register_fn("step", move |context: NativeCallContext| step(&context, &w, 1));
+    let w = world.clone();
+    engine.register_fn("step", move |context: NativeCallContext, n: INT| step(&context, &w, n));
+
+    // 🧩 Motifs
+    let w = world.clone();
+    engine.register_fn("load", move |path: &str| load_pattern(&w, path, None));
+    let w = world.clone();
+    engine.register_fn("load", move |path: &str, x: INT, y: INT| load_pattern(&w, path, Some((x, y))));
+    let w = world.clone();
+    engine.register_fn("save", move |path: &str| -> ScriptResult<()> {
+        let world = lock(&w);
+        let mut pattern = world.grid.extract(0, 0, world.grid.width, world.grid.height);
+        pattern.rule = Some(world.grid.rule);
+        fs::write(path, pattern.to_rle()).map_err(|e| format!("{path} : {e}").into())
+    });
+
+    // 📐 Règle et mutations
+    let w = world.clone();
+    engine.register_fn("rule", move || lock(&w).grid.rule.to_string());
+    let w = world.clone();
+    engine.register_fn("set_rule", move |rule: &str| -> ScriptResult<()> {
+        lock(&w).grid.rule = rule.parse::<Rule>().map_err(|e| e.to_string())?;
+        Ok(())
+    });
+    let w = world.clone();
+    engine.register_fn("mutations", move || -> Map {
+        let settings = lock(&w).settings.clone();
+        let mut map = Map::new();
+        map.insert("blue".into(), settings.allow_blue.into());
+        map.insert("red".into(), settings.allow_red.into());
+        map.insert("blue_prob".into(), probability(settings.blue_probability).into());
+        map.insert("red_prob".into(), probability(settings.red_probability).into());
+        map
+    });
+    let w = world.clone();
+    engine.register_fn("set_mutations", move |changes: Map| -> ScriptResult<()> {
+        let mut settings = lock(&w).settings.clone();
+        for (key, value) in &changes {
+            match key.as_str() {
+                "blue" => settings.allow_blue = flag(value, key)?,
+                "red" => settings.allow_red = flag(value, key)?,
+                "blue_prob" => settings.blue_probability = number(value, key)?.clamp(0.0, 1.0),
+                "red_prob" => settings.red_probability = number(value, key)?.clamp(0.0, 1.0),
+                _ => return Err(format!("réglage inconnu « {key} » (blue, red, blue_prob, red_prob)").into()),
+            }
+        }
+        lock(&w).settings = settings;
+        Ok(())
+    });
+
+    // 📊 Statistiques et rappels
+    let w = world.clone();
+    engine.register_fn("stats", move || stats_map(&lock(&w).stats()));
+    let w = world.clone();
+    engine.register_fn("on_generation", move |callback: FnPtr| lock(&w).callbacks.push(callback));
+}
+
+/// Script de `--script`, lancé une fois la grille créée
+#[derive(Resource, Default)]
+pub struct StartupScript(pub Option<PathBuf>);
+
+pub fn startup_script_system(startup: Res<StartupScript>, mut ev_script: EventWriter<ScriptEvent>) {
+    if let Some(path) = &startup.0 {
+        ev_script.send(ScriptEvent::RunFile(path.clone()));
+    }
+}
+
+// 📜 Scripts demandés, puis rappels `on_generation` des générations calculées par l’application ;
+// la grille, la règle et les mutations modifiées par le script sont recopiées dans l’application
+#[allow(clippy::too_many_arguments)]
+pub fn script_system(
+    mut events: EventReader<ScriptEvent>,
+    mut host: ResMut<ScriptHost>,
+    mut cells: Query<&mut Cell>,
+    mut config: ResMut<GridConfig>,
+    mut settings: ResMut<MutationSettings>,
+    mut rng: ResMut<SimRng>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut edits: ResMut<EditHistory>,
+    loc: Res<Localization>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    let latest = history.latest().copied();
+    let new_generation = latest.filter(|s| host.notified.is_some_and(|n| n != s.generation) && host.has_callbacks());
+    host.notified = latest.map(|s| s.generation);
+    if events.is_empty() && new_generation.is_none() {
+        return;
+    }
+
+    // le script part de la grille affichée et tire dans le même générateur que la simulation (`--seed`)
+    let generation = latest.map_or(0, |s| s.generation);
+    let before = Grid::from_cells(&config, generation, cells.iter().copied());
+    {
+        let mut world = host.world();
+        world.grid = before.clone();
+        world.settings = settings.clone();
+        std::mem::swap(&mut world.rng, &mut rng.0);
+        world.steps.clear();
+    }
+
+    if let Some(stats) = new_generation
+        && let Err(e) = host.notify_generation(stats)
+    {
+        // un rappel en erreur le serait à chaque génération
+        host.clear_callbacks();
+        ev_toast.send(Toast::error(loc.t_with("script-callback-failed", &[("error", e.to_string().into())])));
+    }
+    for event in events.read() {
+        let result = match event {
+            ScriptEvent::RunFile(path) => host.run_file(path).map_err(|e| format!("{} : {e}", path.display())),
+            ScriptEvent::Run(source) => host.run(source).map_err(|e| e.to_string()),
+        };
+        if let Err(e) = result {
+            ev_toast.send(Toast::error(loc.t_with("script-failed", &[("error", e.into())])));
+        }
+    }
+
+    let mut world = host.world();
+    std::mem::swap(&mut world.rng, &mut rng.0);
+    for line in world.output.drain(..) {
+        ev_toast.send(Toast::info(line));
+    }
+    if world.settings != *settings {
+        *settings = world.settings.clone();
+    }
+    if world.grid.rule != config.rule {
+        config.rule = world.grid.rule;
+    }
+    for stats in world.steps.drain(..) {
+        history.push(stats);
+    }
+    let after = &world.grid;
+    if after.cells == before.cells && after.generation == before.generation {
+        return;
+    }
+    apply_grid(after, &mut cells);
+    // cellules modifiées après le dernier `step`
+    if history.latest().map(|s| s.state_hash) != Some(after.stats().state_hash) {
+        history.replace_latest(after.stats());
+    }
+    edits.record(before, after);
+    detector.reset();
+    drop(world);
+    host.notified = history.latest().map(|s| s.generation);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn randomize_rejects_non_finite_density() {
+        let mut host = ScriptHost::default();
+        assert!(host.run("randomize(0.0 / 0.0)").is_err());
+        assert!(host.run("randomize(1.0 / 0.0)").is_err());
+        assert!(host.run("randomize(2.0)").is_ok());
+    }
+
+    #[test]
+    fn scripts_cannot_grow_unbounded_values() {
+        let mut host = ScriptHost::default();
+        assert!(host.run("let s = \"x\"; loop { s += s; }").is_err());
+        assert!(host.run("let a = [0]; loop { a += a; }").is_err());
+        assert!(host.run("let m = #{}; let i = 0; loop { m[`k${i}`] = i; i += 1; }").is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::{
+    camera::{grid_viewport_rect, pointer_over_ui, screen_to_world, GridCamera},
+    cell::Cell,
+    detection::CycleDetector,
+    draw::DrawSettings,
+    edits::EditHistory,
+    generator::Generator,
+    grid::{Grid, GridConfig},
+    mutation_setting::MutationSettings,
+    pattern::Pattern,
+    stats::PopulationHistory,
+    systems::{apply_grid, cell_to_world, world_to_cell, SimRng, CELL_SIZE},
+    theme::Theme
+};
+
+/// Rectangle de cellules : coin haut-gauche et taille
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Region {
+    pub fn from_corners(a: (usize, usize), b: (usize, usize)) -> Region {
+        Region {
+            x: a.0.min(b.0),
+            y: a.1.min(b.1),
+            width: a.0.abs_diff(b.0) + 1,
+            height: a.1.abs_diff(b.1) + 1,
+        }
+    }
+
+    /// Partie de la région qui tient dans une grille width × height
+    pub fn clipped(self, width: usize, height: usize) -> Option<Region> {
+        (self.x < width && self.y < height).then(|| Region {
+            width: self.width.min(width - self.x),
+            height: self.height.min(height - self.y),
+            ..self
+        })
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.y..self.y + self.height).flat_map(move |y| (self.x..self.x + self.width).map(move |x| (x, y)))
+    }
+}
+
+/// Sélection rectangulaire et motif flottant en attente de collage
+#[derive(Resource, Default)]
+pub struct Selection {
+    pub region: Option<Region>,
+    /// Motif collé qui suit le curseur jusqu’au clic
+    pub floating: Option<Pattern>,
+    /// Coin haut-gauche du motif flottant (peut déborder de la grille)
+    pub anchor: Option<(i64, i64)>,
+    drag_start: Option<(usize, usize)>,
+}
+
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub enum SelectionAction {
+    Copy,
+    Cut,
+    Paste,
+    RotateClockwise,
+    FlipHorizontal,
+    FlipVertical,
+    Fill,
+    Clear,
+    Randomize,
+    /// Pose le motif flottant à l’ancre courante
+    Stamp,
+    /// Abandonne le motif flottant, sinon la sélection
+    Cancel,
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct SelectionButton(pub SelectionAction);
+
+#[derive(Component)]
+pub struct SelectionOverlay;
+
+#[derive(Component)]
+pub struct PastePreview;
+
+/// Presse-papiers : copie interne doublée du presse-papiers du système au format RLE,
+/// pour échanger des motifs avec les autres outils
+#[derive(Resource)]
+pub struct PatternClipboard {
+    pattern: Option<Pattern>,
+    system: Option<arboard::Clipboard>,
+}
+
+impl Default for PatternClipboard {
+    fn default() -> Self {
+        Self {
+            pattern: None,
+            // pas de presse-papiers système (session sans affichage…) : copie interne seule
+            system: arboard::Clipboard::new().ok(),
+        }
+    }
+}
+
+impl PatternClipboard {
+    pub fn set(&mut self, pattern: Pattern) {
+        if let Some(system) = &mut self.system
+            && let Err(e) = system.set_text(pattern.to_rle())
+        {
+            warn!("Presse-papiers du système indisponible : {e}");
+        }
+        self.pattern = Some(pattern);
+    }
+
+    /// Le RLE du presse-papiers du système prime (motif copié depuis un autre outil)
+    pub fn get(&mut self) -> Option<Pattern> {
+        self.system
+            .as_mut()
+            .and_then(|s| s.get_text().ok())
+            .and_then(|text| Pattern::parse_rle(&text).ok())
+            .filter(|p| p.width > 0 && p.height > 0)
+            .or_else(|| self.pattern.clone())
+    }
+}
+
+// 🖱️ Clic gauche glissé : sélection (hors mode dessin) ; avec un motif flottant : clic gauche pour coller, clic droit pour abandonner
+#[allow(clippy::too_many_arguments)]
+pub fn selection_mouse_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Transform, &OrthographicProjection), With<GridCamera>>,
+    config: Res<GridConfig>,
+    mut selection: ResMut<Selection>,
+    mut ev_action: EventWriter<SelectionAction>,
+    interactions: Query<&Interaction>,
+    draw: Res<DrawSettings>,
+) {
+    if buttons.just_released(MouseButton::Left) {
+        selection.drag_start = None;
+    }
+    let (Ok(window), Ok((transform, projection))) = (windows.get_single(), cameras.get_single()) else {
+        return;
+    };
+    let cell = window
+        .cursor_position()
+        .filter(|c| grid_viewport_rect(window).contains(*c) && !pointer_over_ui(&interactions))
+        .map(|c| world_to_cell(config.width, config.height, screen_to_world(window, transform, projection.scale, c)));
+
+    // le motif flottant est centré sur le curseur
+    let anchor = match (&selection.floating, cell) {
+        (Some(p), Some((x, y))) => Some((x - p.width as i64 / 2, y - p.height as i64 / 2)),
+        _ => None,
+    };
+    if selection.anchor != anchor {
+        selection.anchor = anchor;
+    }
+
+    let Some((x, y)) = cell else {
+        return;
+    };
+    let inside = x >= 0 && y >= 0 && (x as usize) < config.width && (y as usize) < config.height;
+    let clamped = (
+        x.clamp(0, config.width as i64 - 1) as usize,
+        y.clamp(0, config.height as i64 - 1) as usize,
+    );
+
+    if selection.floating.is_some() {
+        if buttons.just_pressed(MouseButton::Left) {
+            ev_action.send(SelectionAction::Stamp);
+        }
+        if buttons.just_pressed(MouseButton::Right) {
+            ev_action.send(SelectionAction::Cancel);
+        }
+    } else if buttons.just_pressed(MouseButton::Left) && inside && !draw.enabled {
+        selection.drag_start = Some(clamped);
+        selection.region = Some(Region::from_corners(clamped, clamped));
+    } else if buttons.pressed(MouseButton::Left)
+        && let Some(start) = selection.drag_start
+    {
+        let region = Some(Region::from_corners(start, clamped));
+        if selection.region != region {
+            selection.region = region;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn selection_action_system(
+    mut events: EventReader<SelectionAction>,
+    mut selection: ResMut<Selection>,
+    mut clipboard: ResMut<PatternClipboard>,
+    mut query: Query<&mut Cell>,
+    config: Res<GridConfig>,
+    settings: Res<MutationSettings>,
+    generator: Res<Generator>,
+    mut rng: ResMut<SimRng>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut edits: ResMut<EditHistory>,
+) {
+    let actions: Vec<SelectionAction> = events.read().copied().collect();
+    if actions.is_empty() {
+        return;
+    }
+
+    // 1️⃣ Travailler sur une copie de la grille
+    let generation = history.latest().map_or(0, |s| s.generation);
+    let mut grid = Grid::from_cells(&config, generation, query.iter().copied());
+    let before = grid.clone();
+    let mut edited = false;
+
+    for action in actions {
+        let region = selection.region;
+        match action {
+            SelectionAction::Copy | SelectionAction::Cut => {
+                let Some(r) = region else { continue };
+                clipboard.set(grid.extract(r.x, r.y, r.width, r.height));
+                if action == SelectionAction::Cut {
+                    clear(&mut grid, r);
+                    edited = true;
+                }
+            }
+            SelectionAction::Paste => {
+                selection.floating = clipboard.get();
+            }
+            SelectionAction::RotateClockwise | SelectionAction::FlipHorizontal | SelectionAction::FlipVertical => {
+                let transform = |p: &Pattern| match action {
+                    SelectionAction::RotateClockwise => p.rotate_clockwise(),
+                    SelectionAction::FlipHorizontal => p.flip_horizontal(),
+                    _ => p.flip_vertical(),
+                };
+                // le motif flottant en priorité, sinon la sélection sur place
+                if let Some(floating) = selection.floating.as_ref().map(transform) {
+                    selection.floating = Some(floating);
+                } else if let Some(r) = region {
+                    // seule la sélection est effacée : une rotation qui déborde n’efface pas ses voisines
+                    let pattern = transform(&grid.extract(r.x, r.y, r.width, r.height));
+                    clear(&mut grid, r);
+                    grid.overlay(&pattern, r.x as i64, r.y as i64);
+                    selection.region = Region { width: pattern.width, height: pattern.height, ..r }
+                        .clipped(config.width, config.height);
+                    edited = true;
+                }
+            }
+            SelectionAction::Fill | SelectionAction::Clear | SelectionAction::Randomize => {
+                let Some(r) = region else { continue };
+                for (x, y) in r.cells() {
+                    let i = grid.index(x, y);
+                    grid.cells[i] = match action {
+                        SelectionAction::Fill => Cell { alive: true, ..Cell::dead(x, y) },
+                        SelectionAction::Clear => Cell::dead(x, y),
+                        _ => Cell::new(x, y, generator.density(), &settings, &mut rng.0),
+                    };
+                }
+                edited = true;
+            }
+            SelectionAction::Stamp => {
+                if let (Some(pattern), Some((x, y))) = (&selection.floating, selection.anchor) {
+                    grid.stamp(pattern, x, y);
+                    edited = true;
+                }
+            }
+            SelectionAction::Cancel => {
+                if selection.floating.take().is_none() {
+                    selection.region = None;
+                }
+            }
+        }
+    }
+
+    if !edited {
+        return;
+    }
+
+    // 2️⃣ Appliquer les changements aux cellules (annulables)
+    apply_grid(&grid, &mut query);
+    edits.record(before, &grid);
+
+    // 3️⃣ La génération courante a changé : statistiques à jour, détection relancée
+    history.replace_latest(grid.stats());
+    detector.reset();
+}
+
+fn clear(grid: &mut Grid, region: Region) {
+    let empty = Pattern { width: region.width, height: region.height, ..Default::default() };
+    grid.stamp(&empty, region.x as i64, region.y as i64);
+}
+
+// Cadre de la sélection et aperçu du motif flottant, au-dessus des cellules
+#[allow(clippy::type_complexity)]
+pub fn update_selection_overlay(
+    mut commands: Commands,
+    selection: Res<Selection>,
+    config: Res<GridConfig>,
+    theme: Res<Theme>,
+    overlays: Query<Entity, Or<(With<SelectionOverlay>, With<PastePreview>)>>,
+) {
+    if !selection.is_changed() && !theme.is_changed() {
+        return;
+    }
+    for e in &overlays {
+        commands.entity(e).despawn();
+    }
+
+    // centre monde d’une cellule (coordonnées éventuellement hors de la grille)
+    let origin = cell_to_world(config.width, config.height, 0, 0);
+    let at = |x: f32, y: f32| origin + Vec2::new(x * CELL_SIZE, -y * CELL_SIZE);
+    let rect = |x: f32, y: f32, width: usize, height: usize, color: Color, z: f32| SpriteBundle {
+        sprite: Sprite {
+            color,
+            custom_size: Some(Vec2::new(width as f32, height as f32) * CELL_SIZE),
+            ..default()
+        },
+        transform: Transform::from_translation(
+            at(x + (width as f32 - 1.0) / 2.0, y + (height as f32 - 1.0) / 2.0).extend(z),
+        ),
+        ..default()
+    };
+
+    if let Some(r) = selection.region {
+        commands.spawn((rect(r.x as f32, r.y as f32, r.width, r.height, theme.ui.selection, 1.0), SelectionOverlay));
+    }
+
+    if let (Some(pattern), Some((x, y))) = (&selection.floating, selection.anchor) {
+        let (x, y) = (x as f32, y as f32);
+        commands.spawn((rect(x, y, pattern.width, pattern.height, theme.ui.paste_preview, 2.0), PastePreview));
+        for &(px, py, mutation) in &pattern.cells {
+            let mut cell = rect(x + px as f32, y + py as f32, 1, 1, theme.strain(mutation).with_alpha(0.7), 3.0);
+            cell.sprite.custom_size = Some(Vec2::splat(CELL_SIZE - 1.0));
+            commands.spawn((cell, PastePreview));
+        }
+    }
+}
@@ -0,0 +1,594 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TryRecvError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use tungstenite::{handshake::derive_accept_key, protocol::Role, Message, WebSocket};
+use crate::{
+    cell::{Cell, MutationType},
+    detection::CycleDetector,
+    edits::EditHistory,
+    grid::{Boundary, Grid, GridConfig},
+    interface::ResetGridEvent,
+    mutation_setting::MutationSettings,
+    rule::Rule,
+    scripting::{ScriptEvent, MAX_STEPS},
+    shortcuts::Action,
+    stats::{GenerationStats, PopulationHistory},
+    systems::{apply_grid, StepTimer, MAX_SPEED, MIN_SPEED}
+};
+
+/// Port de `--server` donné sans valeur
+pub const DEFAULT_PORT: u16 = 7878;
+/// Au-delà, l’application est considérée comme ne répondant plus
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+/// Taille maximale du corps d’une requête (1 Mio)
+const MAX_BODY: usize = 1 << 20;
+/// Taille maximale de la ligne de requête et des en-têtes (16 Kio)
+const MAX_HEAD: usize = 16 << 10;
+/// Délai de lecture et d’écriture d’une connexion : un client muet ou qui ne lit plus est abandonné
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+/// Messages en attente par client WebSocket ; au-delà, le client trop lent est abandonné
+const STREAM_QUEUE: usize = 64;
+/// Attente d’un message du client WebSocket avant de relayer ceux de l’application
+const STREAM_POLL: Duration = Duration::from_millis(50);
+
+/// Serveur local (127.0.0.1 seulement) lancé par `--server` ; les requêtes sont traitées par `server_system`
+///
+/// Seules les requêtes adressées à `127.0.0.1:<port>` ou `localhost:<port>`, sans `Origin` d’un autre site, sont acceptées :
+/// une page web ouverte dans le navigateur ne peut ni modifier la grille ni s’abonner au flux
+///
+/// - `GET /grid` : cellules vivantes en JSON ; `GET /grid.rle` : grille en RLE
+/// - `GET /stats` : statistiques de la génération courante
+/// - `GET /settings` ; `POST /settings` : `{"speed": 0.1, "paused": false, "rule": "B36/S23", "mutations": {"allow_blue": false}}`, champs facultatifs
+/// - `POST /cells` : `[{"x": 3, "y": 4, "alive": true, "strain": "blue"}]`, annulable d’un coup
+/// - `POST /step?n=10` : n générations (au plus `MAX_STEPS`)
+/// - `POST /reset` : nouvelle grille du générateur ; `POST /reset?to=start` : retour à la génération 0
+/// - `GET /ws` (WebSocket) : grille complète, puis un message à chaque changement (cellules vivantes nouvelles
+///   ou changées de souche, cellules mortes, statistiques)
+#[derive(Resource)]
+pub struct ControlServer {
+    messages: Mutex<Receiver<ServerMessage>>,
+    /// Clients WebSocket (un fil d’envoi chacun)
+    subscribers: Vec<SyncSender<String>>,
+    /// Nouveaux clients, qui recevront la grille complète de `stream_system`
+    pending: Vec<SyncSender<String>>,
+    pub address: SocketAddr,
+}
+
+enum ServerMessage {
+    Request(ApiRequest, Sender<Reply>),
+    Subscribe(SyncSender<String>),
+}
+
+enum ApiRequest {
+    Grid,
+    GridRle,
+    Stats,
+    Settings,
+    ChangeSettings(SettingsChange),
+    Edit(Vec<CellEdit>),
+    Step(u64),
+    Reset { to_start: bool },
+}
+
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Reply {
+    fn json(status: u16, value: &impl Serialize) -> Reply {
+        Reply {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_string(value).unwrap_or_default(),
+        }
+    }
+
+    fn text(body: String) -> Reply {
+        Reply { status: 200, content_type: "text/plain; charset=utf-8", body }
+    }
+
+    fn error(status: u16, message: &str) -> Reply {
+        Reply::json(status, &serde_json::json!({ "error": message }))
+    }
+}
+
+#[derive(Serialize)]
+struct GridJson {
+    width: usize,
+    height: usize,
+    generation: u64,
+    rule: Rule,
+    boundary: Boundary,
+    cells: Vec<LiveCell>,
+}
+
+#[derive(Serialize)]
+struct LiveCell {
+    x: usize,
+    y: usize,
+    strain: MutationType,
+}
+
+#[derive(Serialize)]
+struct StatsJson {
+    generation: u64,
+    total: usize,
+    normal: usize,
+    blue: usize,
+    red: usize,
+    births: usize,
+    deaths: usize,
+}
+
+impl From<GenerationStats> for StatsJson {
+    fn from(stats: GenerationStats) -> Self {
+        Self {
+            generation: stats.generation,
+            total: stats.total,
+            normal: stats.normal,
+            blue: stats.blue,
+            red: stats.red,
+            births: stats.births,
+            deaths: stats.deaths,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SettingsJson<'a> {
+    speed: f32,
+    paused: bool,
+    rule: Rule,
+    mutations: &'a MutationSettings,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SettingsChange {
+    speed: Option<f32>,
+    paused: Option<bool>,
+    rule: Option<Rule>,
+    mutations: Option<MutationsChange>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MutationsChange {
+    allow_blue: Option<bool>,
+    allow_red: Option<bool>,
+    blue_probability: Option<f32>,
+    red_probability: Option<f32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CellEdit {
+    x: usize,
+    y: usize,
+    #[serde(default = "alive_by_default")]
+    alive: bool,
+    #[serde(default)]
+    strain: MutationType,
+}
+
+fn alive_by_default() -> bool {
+    true
+}
+
+/// Message du flux WebSocket
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamMessage {
+    Grid(GridJson),
+    Delta {
+        generation: u64,
+        alive: Vec<LiveCell>,
+        dead: Vec<[usize; 2]>,
+        stats: StatsJson,
+    },
+}
+
+impl ControlServer {
+    /// Écoute sur 127.0.0.1:`port` (0 : port libre choisi par le système)
+    pub fn start(port: u16) -> io::Result<ControlServer> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let address = listener.local_addr()?;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || serve(listener, sender));
+        Ok(ControlServer { messages: Mutex::new(receiver), subscribers: Vec::new(), pending: Vec::new(), address })
+    }
+}
+
+/// Requête HTTP lue sur une connexion (une requête par connexion, `Connection: close`)
+struct HttpRequest {
+    method: String,
+    path: String,
+    query: String,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl HttpRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(field, _)| field.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+    }
+}
+
+/// Fil du serveur : décode les requêtes, les confie à l’application et attend sa réponse
+fn serve(listener: TcpListener, messages: Sender<ServerMessage>) {
+    let port = listener.local_addr().map_or(0, |address| address.port());
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        stream.set_read_timeout(Some(IO_TIMEOUT)).ok();
+        stream.set_write_timeout(Some(IO_TIMEOUT)).ok();
+        let request = match read_request(&mut stream) {
+            Ok(request) => request,
+            Err(reply) => {
+                respond(&mut stream, reply);
+                continue;
+            }
+        };
+        if !is_local(&request, port) {
+            respond(&mut stream, Reply::error(403, "requête refusée : hôte ou origine autre que localhost"));
+            continue;
+        }
+        if request.path == "/ws" {
+            subscribe(stream, &request, &messages);
+            continue;
+        }
+
+        let reply = match parse_request(&request.method, &request.path, &request.query, &request.body) {
+            Err(reply) => reply,
+            Ok(api) => {
+                let (reply_to, reply) = mpsc::channel();
+                // application fermée
+                if messages.send(ServerMessage::Request(api, reply_to)).is_err() {
+                    return;
+                }
+                reply.recv_timeout(REPLY_TIMEOUT).unwrap_or_else(|_| Reply::error(503, "l’application ne répond pas"))
+            }
+        };
+        respond(&mut stream, reply);
+    }
+}
+
+/// Ligne de requête, en-têtes et corps (`Content-Length` obligatoire s’il y en a un)
+fn read_request(stream: &mut TcpStream) -> Result<HttpRequest, Reply> {
+    let incomplete = |e: io::Error| Reply::error(400, &format!("requête incomplète : {e}"));
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let head_len = loop {
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end + 4;
+        }
+        if buffer.len() > MAX_HEAD {
+            return Err(Reply::error(431, "en-têtes trop volumineux"));
+        }
+        match stream.read(&mut chunk) {
+            Ok(0) => return Err(incomplete(ErrorKind::UnexpectedEof.into())),
+            Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+            Err(e) => return Err(incomplete(e)),
+        }
+    };
+
+    let mut fields = [httparse::EMPTY_HEADER; 64];
+    let mut head = httparse::Request::new(&mut fields);
+    if !matches!(head.parse(&buffer[..head_len]), Ok(httparse::Status::Complete(_))) {
+        return Err(Reply::error(400, "requête HTTP invalide"));
+    }
+    let url = head.path.unwrap_or("/");
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let mut request = HttpRequest {
+        method: head.method.unwrap_or_default().to_string(),
+        path: path.to_string(),
+        query: query.to_string(),
+        headers: head.headers.iter().map(|h| (h.name.to_string(), String::from_utf8_lossy(h.value).into_owned())).collect(),
+        body: String::new(),
+    };
+
+    if request.header("Transfer-Encoding").is_some() {
+        return Err(Reply::error(411, "Content-Length attendu"));
+    }
+    let length = match request.header("Content-Length").map(|value| value.trim().parse::<usize>()) {
+        None => 0,
+        Some(Ok(length)) if length <= MAX_BODY => length,
+        Some(Ok(_)) => return Err(Reply::error(413, "corps de la requête trop volumineux")),
+        Some(Err(_)) => return Err(Reply::error(400, "Content-Length invalide")),
+    };
+    let mut body = buffer.split_off(head_len);
+    body.truncate(length);
+    if body.len() < length {
+        let missing = length - body.len();
+        stream.take(missing as u64).read_to_end(&mut body).map_err(incomplete)?;
+        if body.len() < length {
+            return Err(incomplete(ErrorKind::UnexpectedEof.into()));
+        }
+    }
+    request.body = String::from_utf8(body).map_err(|_| Reply::error(400, "corps de la requête non UTF-8"))?;
+    Ok(request)
+}
+
+fn respond(stream: &mut TcpStream, reply: Reply) {
+    let reason = match reply.status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {reason}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        reply.status,
+        reply.content_type,
+        reply.body.len()
+    );
+    let written = stream.write_all(head.as_bytes()).and_then(|_| stream.write_all(reply.body.as_bytes())).and_then(|_| stream.flush());
+    if let Err(e) = written {
+        warn!("Réponse du serveur de contrôle non envoyée : {e}");
+    }
+}
+
+/// `Host` désigne ce serveur par 127.0.0.1 ou localhost (contre le DNS rebinding) et `Origin`, si présent,
+/// est ce même serveur (contre les requêtes et WebSocket envoyés par une autre page)
+fn is_local(request: &HttpRequest, port: u16) -> bool {
+    let local = |host: &str| host == format!("127.0.0.1:{port}") || host == format!("localhost:{port}");
+    let host_ok = request.header("Host").is_some_and(|host| local(&host.to_ascii_lowercase()));
+    let origin_ok = request.header("Origin").is_none_or(|origin| {
+        origin.to_ascii_lowercase().strip_prefix("http://").is_some_and(local)
+    });
+    host_ok && origin_ok
+}
+
+fn parse_request(method: &str, path: &str, query: &str, body: &str) -> Result<ApiRequest, Reply> {
+    let param = |name: &str| query.split('&').filter_map(|p| p.split_once('=')).find(|(key, _)| *key == name).map(|(_, value)| value);
+    let bad_json = |e: serde_json::Error| Reply::error(400, &e.to_string());
+    match (method, path) {
+        ("GET", "/grid") => Ok(ApiRequest::Grid),
+        ("GET", "/grid.rle") => Ok(ApiRequest::GridRle),
+        ("GET", "/stats") => Ok(ApiRequest::Stats),
+        ("GET", "/settings") => Ok(ApiRequest::Settings),
+        ("POST", "/settings") => serde_json::from_str(body).map(ApiRequest::ChangeSettings).map_err(bad_json),
+        ("POST", "/cells") => serde_json::from_str(body).map(ApiRequest::Edit).map_err(bad_json),
+        ("POST", "/step") => match param("n").map(str::parse) {
+            None => Ok(ApiRequest::Step(1)),
+            Some(Ok(n)) if n <= MAX_STEPS as u64 => Ok(ApiRequest::Step(n)),
+            Some(Ok(_)) => Err(Reply::error(400, &format!("n ne peut dépasser {MAX_STEPS}"))),
+            Some(Err(_)) => Err(Reply::error(400, "n doit être un entier positif")),
+        },
+        ("POST", "/reset") => match param("to") {
+            None | Some("random") => Ok(ApiRequest::Reset { to_start: false }),
+            Some("start") => Ok(ApiRequest::Reset { to_start: true }),
+            Some(_) => Err(Reply::error(400, "to attend random ou start")),
+        },
+        (_, "/grid" | "/grid.rle" | "/stats" | "/settings" | "/cells" | "/step" | "/reset") => {
+            Err(Reply::error(405, "méthode non prise en charge"))
+        }
+        _ => Err(Reply::error(404, "ressource inconnue")),
+    }
+}
+
+/// Passage en WebSocket ; un fil relaie ensuite au client les messages de `stream_system`
+/// et lit les siens (Ping, Close), pour remarquer un client parti
+fn subscribe(mut stream: TcpStream, request: &HttpRequest, messages: &Sender<ServerMessage>) {
+    let Some(key) = request.header("Sec-WebSocket-Key") else {
+        respond(&mut stream, Reply::error(400, "WebSocket attendu"));
+        return;
+    };
+    let accept = derive_accept_key(key.trim().as_bytes());
+    let handshake = format!("HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {accept}\r\n\r\n");
+    if stream.write_all(handshake.as_bytes()).is_err() || stream.set_read_timeout(Some(STREAM_POLL)).is_err() {
+        return;
+    }
+
+    // file bornée : `stream_system` abandonne un client qui ne suit plus
+    let (sender, receiver) = mpsc::sync_channel::<String>(STREAM_QUEUE);
+    if messages.send(ServerMessage::Subscribe(sender)).is_err() {
+        return;
+    }
+    thread::spawn(move || {
+        let mut socket = WebSocket::from_raw_socket(stream, Role::Server, None);
+        loop {
+            loop {
+                match receiver.try_recv() {
+                    Ok(text) => {
+                        if socket.send(Message::Text(text)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    // application fermée ou client abandonné
+                    Err(TryRecvError::Disconnected) => {
+                        socket.close(None).ok();
+                        socket.flush().ok();
+                        return;
+                    }
+                }
+            }
+            // les Pong et la réponse au Close partent avec la lecture suivante
+            match socket.read() {
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e)) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(_) => return,
+            }
+        }
+    });
+}
+
+fn grid_json(grid: &Grid) -> GridJson {
+    GridJson {
+        width: grid.width,
+        height: grid.height,
+        generation: grid.generation,
+        rule: grid.rule,
+        boundary: grid.boundary,
+        cells: grid.cells.iter().filter(|c| c.alive).map(|c| LiveCell { x: c.x, y: c.y, strain: c.mutation }).collect(),
+    }
+}
+
+// 🌐 Requêtes du serveur local : lectures, modifications (annulables) et nouveaux clients du flux
+#[allow(clippy::too_many_arguments)]
+pub fn server_system(
+    mut server: ResMut<ControlServer>,
+    mut cells: Query<&mut Cell>,
+    mut config: ResMut<GridConfig>,
+    mut settings: ResMut<MutationSettings>,
+    mut timer: ResMut<StepTimer>,
+    mut history: ResMut<PopulationHistory>,
+    mut detector: ResMut<CycleDetector>,
+    mut edits: ResMut<EditHistory>,
+    mut ev_reset: EventWriter<ResetGridEvent>,
+    mut ev_action: EventWriter<Action>,
+    mut ev_script: EventWriter<ScriptEvent>,
+) {
+    let messages: Vec<ServerMessage> = server.messages.get_mut().unwrap_or_else(|e| e.into_inner()).try_iter().collect();
+    for message in messages {
+        let generation = history.latest().map_or(0, |s| s.generation);
+        let grid = Grid::from_cells(&config, generation, cells.iter().copied());
+        let (api, reply_to) = match message {
+            ServerMessage::Subscribe(subscriber) => {
+                // grille complète envoyée par `stream_system`, avec celle qui sert de base aux différences
+                server.pending.push(subscriber);
+                continue;
+            }
+            ServerMessage::Request(api, reply_to) => (api, reply_to),
+        };
+
+        let reply = match api {
+            ApiRequest::Grid => Reply::json(200, &grid_json(&grid)),
+            ApiRequest::GridRle => {
+                let mut pattern = grid.extract(0, 0, grid.width, grid.height);
+                pattern.rule = Some(grid.rule);
+                Reply::text(pattern.to_rle())
+            }
+            ApiRequest::Stats => Reply::json(200, &StatsJson::from(history.latest().copied().unwrap_or_else(|| grid.stats()))),
+            ApiRequest::Settings => Reply::json(200, &SettingsJson { speed: timer.speed, paused: timer.paused, rule: config.rule, mutations: &settings }),
+            ApiRequest::ChangeSettings(change) => {
+                if let Some(speed) = change.speed {
+                    timer.set_speed(speed.clamp(MAX_SPEED, MIN_SPEED));
+                }
+                if let Some(paused) = change.paused {
+                    timer.paused = paused;
+                }
+                if let Some(rule) = change.rule {
+                    config.rule = rule;
+                }
+                if let Some(mutations) = change.mutations {
+                    let mut changed = settings.clone();
+                    changed.allow_blue = mutations.allow_blue.unwrap_or(changed.allow_blue);
+                    changed.allow_red = mutations.allow_red.unwrap_or(changed.allow_red);
+                    changed.blue_probability = mutations.blue_probability.map_or(changed.blue_probability, |p| p.clamp(0.0, 1.0));
+                    changed.red_probability = mutations.red_probability.map_or(changed.red_probability, |p| p.clamp(0.0, 1.0));
+                    settings.set_if_neq(changed);
+                }
+                Reply::json(200, &SettingsJson { speed: timer.speed, paused: timer.paused, rule: config.rule, mutations: &settings })
+            }
+            ApiRequest::Edit(changes) => match changes.iter().find(|c| c.x >= grid.width || c.y >= grid.height) {
+                Some(outside) => Reply::error(400, &format!("({}, {}) hors de la grille {}×{}", outside.x, outside.y, grid.width, grid.height)),
+                None => {
+                    let mut after = grid.clone();
+                    for change in &changes {
+                        let mutation = if change.alive { change.strain } else { MutationType::None };
+                        let index = after.index(change.x, change.y);
+                        let cell = &mut after.cells[index];
+                        if cell.alive != change.alive || cell.mutation != mutation {
+                            *cell = Cell { alive: change.alive, mutation, ..Cell::dead(change.x, change.y) };
+                        }
+                    }
+                    let stats = after.stats();
+                    if after.cells != grid.cells {
+                        apply_grid(&after, &mut cells);
+                        edits.record(grid, &after);
+                        history.replace_latest(stats);
+                        detector.reset();
+                    }
+                    Reply::json(200, &StatsJson::from(stats))
+                }
+            },
+            ApiRequest::Step(n) => {
+                // calculées par `script_system`, rappels `on_generation` compris
+                ev_script.send(ScriptEvent::Run(format!("step({n})")));
+                Reply::json(202, &serde_json::json!({ "steps": n }))
+            }
+            ApiRequest::Reset { to_start } => {
+                if to_start {
+                    ev_action.send(Action::Reset);
+                } else {
+                    ev_reset.send(ResetGridEvent);
+                }
+                Reply::json(202, &serde_json::json!({ "reset": if to_start { "start" } else { "random" } }))
+            }
+        };
+        // client parti entre-temps : rien à faire
+        reply_to.send(reply).ok();
+    }
+}
+
+// 📡 Flux WebSocket : à chaque changement de la grille, cellules vivantes nouvelles ou changées de souche,
+// cellules mortes et statistiques ; grille complète si ses dimensions changent
+pub fn stream_system(
+    mut server: ResMut<ControlServer>,
+    cells: Query<Ref<Cell>>,
+    config: Res<GridConfig>,
+    history: Res<PopulationHistory>,
+    mut last: Local<Option<Grid>>,
+) {
+    if server.subscribers.is_empty() && server.pending.is_empty() {
+        *last = None;
+        return;
+    }
+    let changed = last.is_none() || history.is_changed() || cells.iter().any(|c| c.is_changed());
+    if !changed && server.pending.is_empty() {
+        return;
+    }
+
+    let generation = history.latest().map_or(0, |s| s.generation);
+    let grid = Grid::from_cells(&config, generation, cells.iter().map(|c| *c));
+    let message = match last.as_ref() {
+        // aucun client jusqu’ici : seuls les nouveaux, servis plus bas
+        None => None,
+        Some(previous) if (previous.width, previous.height) != (grid.width, grid.height) => Some(StreamMessage::Grid(grid_json(&grid))),
+        Some(previous) => {
+            let mut alive = Vec::new();
+            let mut dead = Vec::new();
+            for (before, now) in previous.cells.iter().zip(&grid.cells) {
+                if now.alive && (!before.alive || before.mutation != now.mutation) {
+                    alive.push(LiveCell { x: now.x, y: now.y, strain: now.mutation });
+                } else if before.alive && !now.alive {
+                    dead.push([now.x, now.y]);
+                }
+            }
+            (!alive.is_empty() || !dead.is_empty() || previous.generation != grid.generation).then(|| StreamMessage::Delta {
+                generation: grid.generation,
+                alive,
+                dead,
+                stats: StatsJson::from(history.latest().copied().unwrap_or_else(|| grid.stats())),
+            })
+        }
+    };
+    if let Some(message) = message {
+        let text = serde_json::to_string(&message).unwrap_or_default();
+        server.subscribers.retain(|subscriber| subscriber.try_send(text.clone()).is_ok());
+    }
+    // les nouveaux clients partent de la grille qui sert de base aux différences suivantes
+    if !server.pending.is_empty() {
+        let snapshot = serde_json::to_string(&StreamMessage::Grid(grid_json(&grid))).unwrap_or_default();
+        let pending = std::mem::take(&mut server.pending);
+        server.subscribers.extend(pending.into_iter().filter(|subscriber| subscriber.try_send(snapshot.clone()).is_ok()));
+    }
+    *last = Some(grid);
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::{
+    cell::Cell,
+    grid::{Boundary, Grid, GridConfig},
+    mutation_setting::MutationSettings,
+    pattern::{Pattern, PatternError},
+    rule::Rule,
+    stats::PopulationHistory,
+    toast::Toast,
+    locale::Localization
+};
+
+/// Extension des fichiers de session
+pub const SESSION_EXTENSION: &str = "gol";
+const SESSION_VERSION: u32 = 1;
+
+/// État complet d’une partie : dimensions, règle, mutations et cellules (RLE)
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub version: u32,
+    pub width: usize,
+    pub height: usize,
+    pub boundary: Boundary,
+    pub rule: Rule,
+    pub mutations: MutationSettings,
+    pub generation: u64,
+    /// Cellules au format RLE, états étendus pour les mutations
+    pub cells: String,
+}
+
+#[derive(Event)]
+pub struct SaveSessionEvent;
+
+impl Session {
+    pub fn from_grid(grid: &Grid, settings: &MutationSettings) -> Session {
+        Session {
+            version: SESSION_VERSION,
+            width: grid.width,
+            height: grid.height,
+            boundary: grid.boundary,
+            rule: grid.rule,
+            mutations: settings.clone(),
+            generation: grid.generation,
+            cells: grid.extract(0, 0, grid.width, grid.height).to_rle(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Session, PatternError> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| PatternError::Parse(e.to_string()))
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    pub fn grid_config(&self) -> GridConfig {
+        GridConfig {
+            width: self.width.max(1),
+            height: self.height.max(1),
+            boundary: self.boundary,
+            rule: self.rule,
+        }
+    }
+
+    pub fn grid(&self) -> Result<Grid, PatternError> {
+        let mut grid = Grid::empty(&self.grid_config());
+        grid.stamp(&Pattern::parse_rle(&self.cells)?, 0, 0);
+        grid.generation = self.generation;
+        Ok(grid)
+    }
+}
+
+/// Nom de fichier par défaut : session_<horodatage>.gol dans le dossier courant
+pub fn default_session_path() -> PathBuf {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    PathBuf::from(format!("session_{secs}.{SESSION_EXTENSION}"))
+}
+
+/// Session la plus récente du dossier courant (celle que recharge Ctrl+O)
+pub fn latest_session_path() -> Option<PathBuf> {
+    fs::read_dir(".")
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|e| e.to_string_lossy().eq_ignore_ascii_case(SESSION_EXTENSION)))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .max()
+        .map(|(_, path)| path)
+}
+
+pub fn save_session_system(
+    mut events: EventReader<SaveSessionEvent>,
+    query: Query<&Cell>,
+    config: Res<GridConfig>,
+    settings: Res<MutationSettings>,
+    history: Res<PopulationHistory>,
+    loc: Res<Localization>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    for _ in events.read() {
+        let generation = history.latest().map_or(0, |s| s.generation);
+        let grid = Grid::from_cells(&config, generation, query.iter().copied());
+        let path = default_session_path();
+        ev_toast.send(match Session::from_grid(&grid, &settings).save(&path) {
+            Ok(()) => Toast::info(loc.t_with("session-saved", &[("path", path.display().to_string().into())])),
+            Err(e) => Toast::error(loc.t_with("session-failed", &[("path", path.display().to_string().into()), ("error", e.to_string().into())])),
+        });
+    }
+}
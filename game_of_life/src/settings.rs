@@ -0,0 +1,228 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use clap::{parser::ValueSource, ArgMatches};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::{
+    cli::Cli,
+    generator::{Generator, MAX_REGIONS},
+    mutation_setting::MutationSettings,
+    systems::{StepTimer, DEFAULT_SPEED},
+    theme::{PaletteName, ThemeName},
+    toast::Toast,
+    locale::{Language, Localization}
+};
+
+/// Délai entre la dernière modification et l’écriture du fichier (un curseur qu’on fait glisser
+/// change les réglages à chaque image)
+const SAVE_DELAY: f32 = 1.0;
+
+/// Taille de la fenêtre au premier lancement
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for WindowSize {
+    fn default() -> Self {
+        Self { width: 1280.0, height: 720.0 }
+    }
+}
+
+/// Préférences gardées d’un lancement à l’autre
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserSettings {
+    pub mutations: MutationSettings,
+    /// Générateur de « Nouvelle Grille »
+    pub generator: Generator,
+    /// Durée d’une étape en secondes
+    pub speed: f32,
+    pub theme: ThemeName,
+    pub palette: Option<PaletteName>,
+    /// Langue du système si absente
+    pub language: Option<Language>,
+    pub window: WindowSize,
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        Self {
+            mutations: MutationSettings::default(),
+            generator: Generator::default(),
+            speed: DEFAULT_SPEED,
+            theme: ThemeName::default(),
+            palette: None,
+            language: None,
+            window: WindowSize::default(),
+        }
+    }
+}
+
+/// Emplacement du fichier de réglages
+#[derive(Resource)]
+pub struct SettingsFile(pub Option<PathBuf>);
+
+#[derive(Component)]
+pub struct ResetSettingsButton;
+
+impl UserSettings {
+    /// Fichier JSON ; les réglages absents gardent leur valeur par défaut
+    pub fn load(path: &Path) -> io::Result<UserSettings> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(io::Error::other)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Les options données sur la ligne de commande l’emportent et sont retenues ;
+    /// les autres prennent la valeur enregistrée
+    pub fn merge_cli(&mut self, cli: &mut Cli, matches: &ArgMatches) {
+        let given = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+        let simulation = &mut cli.simulation;
+
+        if given("no_blue") {
+            self.mutations.allow_blue = !simulation.no_blue;
+        } else {
+            simulation.no_blue = !self.mutations.allow_blue;
+        }
+        if given("no_red") {
+            self.mutations.allow_red = !simulation.no_red;
+        } else {
+            simulation.no_red = !self.mutations.allow_red;
+        }
+        if given("blue_prob") {
+            self.mutations.blue_probability = simulation.blue_prob;
+        } else {
+            simulation.blue_prob = self.mutations.blue_probability;
+        }
+        if given("red_prob") {
+            self.mutations.red_probability = simulation.red_prob;
+        } else {
+            simulation.red_prob = self.mutations.red_probability;
+        }
+        let generator = &mut simulation.generator;
+        merge(given("kind"), &mut self.generator.kind, &mut generator.kind);
+        merge(given("density"), &mut self.generator.density, &mut generator.density);
+        merge(given("soup_size"), &mut self.generator.soup_size, &mut generator.soup_size);
+        merge(given("symmetry"), &mut self.generator.symmetry, &mut generator.symmetry);
+        merge(given("noise_scale"), &mut self.generator.noise_scale, &mut generator.noise_scale);
+        merge(given("regions"), &mut self.generator.regions, &mut generator.regions);
+        // valeur enregistrée hors de la plage du réglage
+        self.generator.regions = self.generator.regions.clamp(1, MAX_REGIONS);
+        generator.regions = self.generator.regions;
+        merge(given("speed"), &mut self.speed, &mut cli.speed);
+        merge(given("theme"), &mut self.theme, &mut cli.appearance.theme);
+        merge(given("palette"), &mut self.palette, &mut cli.appearance.palette);
+        merge(given("lang"), &mut self.language, &mut cli.lang);
+    }
+
+    /// Fenêtre principale à la taille enregistrée
+    pub fn window(&self) -> Window {
+        Window {
+            resolution: (self.window.width.max(200.0), self.window.height.max(200.0)).into(),
+            ..default()
+        }
+    }
+}
+
+/// Valeur de la ligne de commande retenue si elle y est donnée, sinon valeur enregistrée
+fn merge<T: Clone>(given: bool, saved: &mut T, cli: &mut T) {
+    if given {
+        *saved = cli.clone();
+    } else {
+        *cli = saved.clone();
+    }
+}
+
+/// Fichier lu au lancement et réécrit à chaque changement
+pub fn default_settings_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("game_of_life").join("settings.json"))
+}
+
+// 🔄 Réglages courants ← mutations, générateur, vitesse, langue et taille de la fenêtre
+pub fn sync_settings(
+    mutations: Res<MutationSettings>,
+    generator: Res<Generator>,
+    timer: Res<StepTimer>,
+    loc: Res<Localization>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut settings: ResMut<UserSettings>,
+) {
+    let mut current = settings.clone();
+    current.mutations = mutations.clone();
+    current.generator = generator.clone();
+    current.speed = timer.speed;
+    current.language = Some(loc.language());
+    if let Ok(window) = windows.get_single() {
+        current.window = WindowSize { width: window.width(), height: window.height() };
+    }
+    settings.set_if_neq(current);
+}
+
+// 💾 Écriture du fichier un peu après le dernier changement, et à la fermeture
+pub fn save_settings_system(
+    time: Res<Time>,
+    settings: Res<UserSettings>,
+    file: Res<SettingsFile>,
+    mut exit: EventReader<AppExit>,
+    mut pending: Local<Option<f32>>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        *pending = Some(time.elapsed_seconds() + SAVE_DELAY);
+    }
+    let exiting = !exit.is_empty();
+    exit.clear();
+    let Some(deadline) = *pending else {
+        return;
+    };
+    if !exiting && time.elapsed_seconds() < deadline {
+        return;
+    }
+    *pending = None;
+
+    if let Some(path) = &file.0
+        && let Err(e) = settings.save(path)
+    {
+        warn!("Réglages non enregistrés dans {} : {e}", path.display());
+    }
+}
+
+// ↺ « Réglages par défaut » : mutations, générateur, vitesse, langue et fenêtre tout de suite, thème au prochain lancement
+#[allow(clippy::too_many_arguments)]
+pub fn reset_settings_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ResetSettingsButton>)>,
+    mut settings: ResMut<UserSettings>,
+    mut mutations: ResMut<MutationSettings>,
+    mut generator: ResMut<Generator>,
+    mut timer: ResMut<StepTimer>,
+    mut loc: ResMut<Localization>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    for interaction in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let defaults = UserSettings::default();
+        *mutations = defaults.mutations.clone();
+        *generator = defaults.generator.clone();
+        timer.set_speed(defaults.speed);
+        loc.set_language(Language::detect());
+        if let Ok(mut window) = windows.get_single_mut() {
+            window.resolution.set(defaults.window.width, defaults.window.height);
+        }
+        *settings = defaults;
+        ev_toast.send(Toast::info(loc.t("settings-reset")));
+    }
+}
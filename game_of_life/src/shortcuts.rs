@@ -0,0 +1,474 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use crate::{
+    camera::ViewEvent,
+    draw::DrawSettings,
+    export::ExportEvent,
+    file_drop::OpenFileEvent,
+    interface::{HelpOverlay, ResetGridEvent},
+    locale::Localization,
+    selection::SelectionAction,
+    session::{latest_session_path, SaveSessionEvent},
+    systems::{GridLines, StepTimer, MAX_SPEED, MIN_SPEED},
+    toast::Toast,
+    widgets::WidgetFocus
+};
+
+/// Une pression sur « plus vite » divise la durée d’une étape par ce facteur
+const SPEED_FACTOR: f32 = 1.25;
+/// Crans de molette équivalents à une pression sur une touche de zoom
+const ZOOM_STEPS: f32 = 2.0;
+
+/// Tout ce qu’on peut faire au clavier ; les noms servent aussi dans le fichier de touches
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Action {
+    PlayPause,
+    /// Une génération (en pause)
+    Step,
+    SpeedUp,
+    SpeedDown,
+    /// Nouvelle grille aléatoire
+    Randomize,
+    /// Retour à la génération 0
+    Reset,
+    Clear,
+    Undo,
+    Redo,
+    ZoomIn,
+    ZoomOut,
+    FitView,
+    ResetView,
+    PanLeft,
+    PanRight,
+    PanUp,
+    PanDown,
+    GridLines,
+    Save,
+    /// Dernière session enregistrée dans le dossier courant
+    Load,
+    ExportImage,
+    Copy,
+    Cut,
+    Paste,
+    Rotate,
+    FlipHorizontal,
+    FlipVertical,
+    Fill,
+    ClearSelection,
+    RandomizeSelection,
+    /// Mode dessin (symétrique) à la place de la sélection
+    DrawMode,
+    /// Console de commandes
+    Console,
+    Cancel,
+    Help,
+}
+
+impl Action {
+    /// Ordre de l’aide
+    pub const ALL: [Action; 34] = [
+        Action::PlayPause, Action::Step, Action::SpeedUp, Action::SpeedDown,
+        Action::Randomize, Action::Reset, Action::Clear, Action::Undo, Action::Redo,
+        Action::ZoomIn, Action::ZoomOut, Action::FitView, Action::ResetView,
+        Action::PanLeft, Action::PanRight, Action::PanUp, Action::PanDown, Action::GridLines,
+        Action::Save, Action::Load, Action::ExportImage,
+        Action::Copy, Action::Cut, Action::Paste, Action::Rotate, Action::FlipHorizontal, Action::FlipVertical,
+        Action::Fill, Action::ClearSelection, Action::RandomizeSelection, Action::DrawMode, Action::Console, Action::Cancel, Action::Help,
+    ];
+
+    /// Actions qui durent tant que la touche est enfoncée (voir `HeldActions`)
+    pub fn is_continuous(self) -> bool {
+        matches!(self, Action::PanLeft | Action::PanRight | Action::PanUp | Action::PanDown)
+    }
+
+    /// Message de l’aide : « action-<nom> »
+    pub fn message(self) -> String {
+        let name = serde_json::to_value(self).ok().and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default();
+        format!("action-{name}")
+    }
+
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::PlayPause => &["Space"],
+            Action::Step => &["Enter", "."],
+            Action::SpeedUp => &["+", "="],
+            Action::SpeedDown => &["-"],
+            Action::Randomize => &["Ctrl+N"],
+            Action::Reset => &["Ctrl+R"],
+            Action::Clear => &["Ctrl+Delete", "Ctrl+Backspace"],
+            Action::Undo => &["Ctrl+Z"],
+            Action::Redo => &["Ctrl+Y", "Ctrl+Shift+Z"],
+            Action::ZoomIn => &["PageUp", "Ctrl++", "Ctrl+="],
+            Action::ZoomOut => &["PageDown", "Ctrl+-"],
+            Action::FitView => &["Home"],
+            Action::ResetView => &["End"],
+            Action::PanLeft => &["ArrowLeft"],
+            Action::PanRight => &["ArrowRight"],
+            Action::PanUp => &["ArrowUp"],
+            Action::PanDown => &["ArrowDown"],
+            Action::GridLines => &["G"],
+            Action::Save => &["Ctrl+S"],
+            Action::Load => &["Ctrl+O"],
+            Action::ExportImage => &["Ctrl+E"],
+            Action::Copy => &["Ctrl+C"],
+            Action::Cut => &["Ctrl+X"],
+            Action::Paste => &["Ctrl+V"],
+            Action::Rotate => &["R"],
+            Action::FlipHorizontal => &["H"],
+            Action::FlipVertical => &["V"],
+            Action::Fill => &["F"],
+            Action::ClearSelection => &["Delete", "Backspace"],
+            Action::RandomizeSelection => &["A"],
+            Action::DrawMode => &["D"],
+            Action::Console => &["`", "F12"],
+            Action::Cancel => &["Escape"],
+            Action::Help => &["?", "F1"],
+        }
+    }
+
+    /// Action équivalente sur la sélection
+    fn selection(self) -> Option<SelectionAction> {
+        Some(match self {
+            Action::Copy => SelectionAction::Copy,
+            Action::Cut => SelectionAction::Cut,
+            Action::Paste => SelectionAction::Paste,
+            Action::Rotate => SelectionAction::RotateClockwise,
+            Action::FlipHorizontal => SelectionAction::FlipHorizontal,
+            Action::FlipVertical => SelectionAction::FlipVertical,
+            Action::Fill => SelectionAction::Fill,
+            Action::ClearSelection => SelectionAction::Clear,
+            Action::RandomizeSelection => SelectionAction::Randomize,
+            Action::Cancel => SelectionAction::Cancel,
+            _ => return None,
+        })
+    }
+}
+
+/// Touches de modification enfoncées (Cmd compte comme Ctrl)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl Modifiers {
+    pub fn current(keys: &ButtonInput<KeyCode>) -> Modifiers {
+        Modifiers {
+            ctrl: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight]),
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        }
+    }
+}
+
+/// Combinaison de touches, écrite « Ctrl+Shift+Z », « Space », « ? »…
+///
+/// La touche est celle du caractère produit (disposition du clavier comprise) : « ? » vaut
+/// Maj+, sur un clavier AZERTY comme Maj+/ sur un clavier QWERTY
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyBinding {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+/// Touches nommées reconnues dans les fichiers de touches
+const NAMED_KEYS: [(&str, Key); 15] = [
+    ("Space", Key::Space),
+    ("Enter", Key::Enter),
+    ("Tab", Key::Tab),
+    ("Escape", Key::Escape),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Insert", Key::Insert),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("ArrowLeft", Key::ArrowLeft),
+    ("ArrowRight", Key::ArrowRight),
+    ("ArrowUp", Key::ArrowUp),
+    ("ArrowDown", Key::ArrowDown),
+];
+
+const FUNCTION_KEYS: [Key; 12] = [
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6,
+    Key::F7, Key::F8, Key::F9, Key::F10, Key::F11, Key::F12,
+];
+
+impl KeyBinding {
+    /// Même touche et mêmes modificateurs ; Maj est ignorée pour les symboles (« ? », « + »)
+    /// qu’elle sert souvent à taper
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let symbol = matches!(&self.key, Key::Character(c) if !c.chars().any(char::is_alphanumeric));
+        same_key(&self.key, key)
+            && self.modifiers.ctrl == modifiers.ctrl
+            && self.modifiers.alt == modifiers.alt
+            && (self.modifiers.shift == modifiers.shift || (symbol && !self.modifiers.shift))
+    }
+}
+
+fn same_key(a: &Key, b: &Key) -> bool {
+    match (a, b) {
+        (Key::Character(a), Key::Character(b)) => a.to_lowercase() == b.to_lowercase(),
+        _ => a == b,
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::default();
+        let mut rest = text.trim();
+        // « Ctrl++ » : le dernier « + » est la touche
+        while let Some((prefix, tail)) = rest.split_once('+').filter(|(_, tail)| !tail.is_empty()) {
+            match prefix.to_ascii_lowercase().as_str() {
+                "ctrl" | "cmd" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" => modifiers.alt = true,
+                _ => return Err(format!("modificateur inconnu « {prefix} » dans « {text} »")),
+            }
+            rest = tail;
+        }
+
+        let key = if let Some((_, key)) = NAMED_KEYS.iter().find(|(name, _)| name.eq_ignore_ascii_case(rest)) {
+            key.clone()
+        } else if let Some(n) = rest.strip_prefix(['F', 'f']).and_then(|n| n.parse::<usize>().ok()).filter(|n| (1..=12).contains(n)) {
+            FUNCTION_KEYS[n - 1].clone()
+        } else if rest.chars().count() == 1 {
+            Key::Character(rest.to_lowercase().into())
+        } else {
+            return Err(format!("touche inconnue « {rest} » dans « {text} »"));
+        };
+        Ok(KeyBinding { modifiers, key })
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+        if let Some(n) = FUNCTION_KEYS.iter().position(|k| *k == self.key) {
+            return write!(f, "F{}", n + 1);
+        }
+        match (&self.key, NAMED_KEYS.iter().find(|(_, key)| *key == self.key)) {
+            (_, Some((name, _))) => write!(f, "{name}"),
+            (Key::Character(c), _) => write!(f, "{}", c.to_uppercase()),
+            (key, _) => write!(f, "{key:?}"),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<KeyBinding> for String {
+    fn from(binding: KeyBinding) -> String {
+        binding.to_string()
+    }
+}
+
+/// Touches de chaque action
+#[derive(Resource, Clone, Debug)]
+pub struct KeyBindings {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let keys = action.default_keys().iter().map(|k| k.parse().expect("touche par défaut valide")).collect();
+                (*action, keys)
+            })
+            .collect();
+        KeyBindings { bindings }
+    }
+}
+
+impl KeyBindings {
+    /// Fichier JSON `{ "undo": ["Ctrl+Z"], "help": ["F1"] }` ; les actions absentes gardent leurs touches
+    pub fn load(path: &Path) -> io::Result<KeyBindings> {
+        let text = fs::read_to_string(path)?;
+        let overrides: HashMap<Action, Vec<KeyBinding>> = serde_json::from_str(&text).map_err(io::Error::other)?;
+        let mut bindings = KeyBindings::default();
+        bindings.bindings.extend(overrides);
+        Ok(bindings)
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Actions déclenchées par la touche
+    pub fn actions(&self, key: &Key, modifiers: Modifiers) -> impl Iterator<Item = Action> + '_ {
+        let key = key.clone();
+        Action::ALL.into_iter().filter(move |action| self.keys(*action).iter().any(|b| b.matches(&key, modifiers)))
+    }
+}
+
+/// Fichier de touches lu au lancement s’il existe (sinon `--keys`)
+pub fn default_keys_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("game_of_life").join("keys.json"))
+}
+
+/// Actions continues en cours (touche encore enfoncée)
+#[derive(Resource, Default, PartialEq)]
+pub struct HeldActions(HashSet<Action>);
+
+impl HeldActions {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.0.contains(&action)
+    }
+}
+
+// ⌨️ Touches → actions, sauf quand un widget du panneau a le clavier ;
+// la répétition automatique d’une touche maintenue est ignorée
+pub fn shortcut_system(
+    mut keyboard: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<WidgetFocus>,
+    bindings: Res<KeyBindings>,
+    mut held_keys: Local<HashMap<KeyCode, Key>>,
+    mut held: ResMut<HeldActions>,
+    mut ev_action: EventWriter<Action>,
+) {
+    if focus.captures_keyboard() {
+        keyboard.clear();
+        held_keys.clear();
+        held.set_if_neq(HeldActions::default());
+        return;
+    }
+
+    let modifiers = Modifiers::current(&keys);
+    for input in keyboard.read() {
+        match input.state {
+            ButtonState::Pressed => {
+                if held_keys.insert(input.key_code, input.logical_key.clone()).is_some() {
+                    continue;
+                }
+                for action in bindings.actions(&input.logical_key, modifiers).filter(|a| !a.is_continuous()) {
+                    ev_action.send(action);
+                }
+            }
+            ButtonState::Released => {
+                held_keys.remove(&input.key_code);
+            }
+        }
+    }
+
+    // les actions continues ignorent les modificateurs (Maj+flèche déplace aussi la vue)
+    let continuous = Action::ALL
+        .into_iter()
+        .filter(|action| action.is_continuous())
+        .filter(|action| held_keys.values().any(|key| bindings.keys(*action).iter().any(|b| same_key(&b.key, key))))
+        .collect();
+    held.set_if_neq(HeldActions(continuous));
+}
+
+// 🎛️ Actions qui reviennent aux boutons du panneau (la grille et l’historique des modifications
+// sont traités par `edit_action_system`, le pas à pas par `update_cells`)
+#[allow(clippy::too_many_arguments)]
+pub fn action_system(
+    mut actions: EventReader<Action>,
+    mut timer: ResMut<StepTimer>,
+    mut grid_lines: ResMut<GridLines>,
+    mut draw: ResMut<DrawSettings>,
+    mut help: Query<&mut Visibility, With<HelpOverlay>>,
+    mut ev_reset: EventWriter<ResetGridEvent>,
+    mut ev_view: EventWriter<ViewEvent>,
+    mut ev_selection: EventWriter<SelectionAction>,
+    mut ev_save: EventWriter<SaveSessionEvent>,
+    mut ev_open: EventWriter<OpenFileEvent>,
+    mut ev_export: EventWriter<ExportEvent>,
+    loc: Res<Localization>,
+    mut ev_toast: EventWriter<Toast>,
+) {
+    for &action in actions.read() {
+        if let Some(selection) = action.selection() {
+            ev_selection.send(selection);
+            // Échap ferme aussi l’aide
+            if action == Action::Cancel {
+                for mut visibility in &mut help {
+                    *visibility = Visibility::Hidden;
+                }
+            }
+            continue;
+        }
+        match action {
+            Action::PlayPause => timer.paused = !timer.paused,
+            Action::SpeedUp => {
+                let speed = (timer.speed / SPEED_FACTOR).clamp(MAX_SPEED, MIN_SPEED);
+                timer.set_speed(speed);
+            }
+            Action::SpeedDown => {
+                let speed = (timer.speed * SPEED_FACTOR).clamp(MAX_SPEED, MIN_SPEED);
+                timer.set_speed(speed);
+            }
+            Action::Randomize => {
+                ev_reset.send(ResetGridEvent);
+            }
+            Action::ZoomIn => {
+                ev_view.send(ViewEvent::Zoom(ZOOM_STEPS));
+            }
+            Action::ZoomOut => {
+                ev_view.send(ViewEvent::Zoom(-ZOOM_STEPS));
+            }
+            Action::FitView => {
+                ev_view.send(ViewEvent::FitPattern);
+            }
+            Action::ResetView => {
+                ev_view.send(ViewEvent::Reset);
+            }
+            Action::GridLines => grid_lines.0 = !grid_lines.0,
+            Action::DrawMode => draw.enabled = !draw.enabled,
+            Action::Save => {
+                ev_save.send(SaveSessionEvent);
+            }
+            Action::Load => match latest_session_path() {
+                Some(path) => {
+                    ev_open.send(OpenFileEvent(path));
+                }
+                None => {
+                    ev_toast.send(Toast::error(loc.t("session-none")));
+                }
+            },
+            Action::ExportImage => {
+                ev_export.send(ExportEvent::SaveImage);
+            }
+            Action::Help => {
+                for mut visibility in &mut help {
+                    *visibility = match *visibility {
+                        Visibility::Hidden => Visibility::Inherited,
+                        _ => Visibility::Hidden,
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
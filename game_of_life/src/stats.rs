@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use crate::cell::{Cell, MutationType};
+
+/// Nombre de générations conservées dans l’historique (et affichées par le graphe)
+pub const HISTORY_LEN: usize = 100;
+
+/// Statistiques d’une génération
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GenerationStats {
+    pub generation: u64,
+    pub total: usize,
+    pub normal: usize,
+    pub blue: usize,
+    pub red: usize,
+    pub births: usize,
+    pub deaths: usize,
+    /// Boîte englobante des cellules vivantes (min_x, min_y, max_x, max_y)
+    pub bounds: Option<(usize, usize, usize, usize)>,
+    /// Empreinte des positions vivantes (indépendante de l’ordre et des mutations)
+    pub state_hash: u64,
+}
+
+impl GenerationStats {
+    /// Ajoute une cellule vivante au comptage de sa souche
+    pub fn count(&mut self, cell: &Cell) {
+        if !cell.alive {
+            return;
+        }
+        self.total += 1;
+        self.bounds = Some(match self.bounds {
+            None => (cell.x, cell.y, cell.x, cell.y),
+            Some((min_x, min_y, max_x, max_y)) => (
+                min_x.min(cell.x),
+                min_y.min(cell.y),
+                max_x.max(cell.x),
+                max_y.max(cell.y),
+            ),
+        });
+        // somme de hachages par cellule : l’ordre de parcours de la requête n’a pas d’importance
+        let mut hasher = DefaultHasher::new();
+        (cell.x, cell.y).hash(&mut hasher);
+        self.state_hash = self.state_hash.wrapping_add(hasher.finish());
+        match cell.mutation {
+            MutationType::None => self.normal += 1,
+            MutationType::Blue => self.blue += 1,
+            MutationType::Red => self.red += 1,
+        }
+    }
+
+    /// Population d’une souche donnée
+    pub fn strain(&self, mutation: MutationType) -> usize {
+        match mutation {
+            MutationType::None => self.normal,
+            MutationType::Blue => self.blue,
+            MutationType::Red => self.red,
+        }
+    }
+}
+
+/// Historique glissant des dernières générations
+#[derive(Resource, Default)]
+pub struct PopulationHistory {
+    pub samples: VecDeque<GenerationStats>,
+}
+
+impl PopulationHistory {
+    pub fn push(&mut self, stats: GenerationStats) {
+        if self.samples.len() == HISTORY_LEN {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(stats);
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn latest(&self) -> Option<&GenerationStats> {
+        self.samples.back()
+    }
+
+    /// Remplace l’état de la génération courante (grille modifiée à la main)
+    pub fn replace_latest(&mut self, stats: GenerationStats) {
+        self.samples.pop_back();
+        self.samples.push_back(stats);
+    }
+
+    /// Génération suivante à enregistrer
+    pub fn next_generation(&self) -> u64 {
+        self.latest().map_or(0, |s| s.generation + 1)
+    }
+}
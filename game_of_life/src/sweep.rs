@@ -0,0 +1,231 @@
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use crate::{
+    batch::{run_soup, SoupConfig, SoupResult},
+    rule::Rule
+};
+
+/// Valeurs au plus dans une plage
+pub const MAX_VALUES: usize = 1000;
+
+/// Plage de valeurs "début:fin:pas" (bornes incluses) ou valeur unique
+#[derive(Clone, Copy, Debug)]
+pub struct ValueRange {
+    pub start: f32,
+    pub end: f32,
+    pub step: f32,
+}
+
+impl ValueRange {
+    pub fn values(&self) -> Vec<f32> {
+        if self.step <= 0.0 || self.end <= self.start {
+            return vec![self.start];
+        }
+        let count = (((self.end - self.start) / self.step + 1e-4).floor() as usize).min(MAX_VALUES - 1);
+        // calcul par multiplication (pas d’erreur cumulée), arrondi pour des sorties lisibles
+        (0..=count)
+            .map(|i| ((self.start + i as f32 * self.step) * 1e6).round() / 1e6)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum RangeParseError {
+    /// Pas de la forme début:fin:pas, nombre non fini ou pas nul ou négatif
+    Invalid(String),
+    /// Plus de `MAX_VALUES` valeurs
+    TooManyValues(String),
+}
+
+impl fmt::Display for RangeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeParseError::Invalid(s) => write!(f, "plage invalide « {s} » (attendu début:fin:pas, ex. 0:0.3:0.05, avec un pas positif)"),
+            RangeParseError::TooManyValues(s) => write!(f, "plage « {s} » trop longue (au plus {MAX_VALUES} valeurs)"),
+        }
+    }
+}
+
+impl std::error::Error for RangeParseError {}
+
+impl FromStr for ValueRange {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Result<Vec<f32>, _> = s.split(':').map(|p| p.trim().parse::<f32>()).collect();
+        let range = match parts.as_deref() {
+            Ok([value]) => ValueRange { start: *value, end: *value, step: 0.0 },
+            Ok([start, end, step]) if *step > 0.0 => ValueRange { start: *start, end: *end, step: *step },
+            _ => return Err(RangeParseError::Invalid(s.to_string())),
+        };
+        if ![range.start, range.end, range.step].iter().all(|v| v.is_finite()) {
+            return Err(RangeParseError::Invalid(s.to_string()));
+        }
+        if range.end > range.start && (range.end - range.start) / range.step >= MAX_VALUES as f32 {
+            return Err(RangeParseError::TooManyValues(s.to_string()));
+        }
+        Ok(range)
+    }
+}
+
+/// Toutes les combinaisons à explorer ; chacune est exécutée avec `seeds` graines
+pub struct SweepSpec {
+    pub base: SoupConfig,
+    pub blue_probabilities: Vec<f32>,
+    pub red_probabilities: Vec<f32>,
+    pub rules: Vec<Rule>,
+    pub first_seed: u64,
+    pub seeds: u64,
+}
+
+impl SweepSpec {
+    /// Produit cartésien probabilité bleue × probabilité rouge × règle
+    pub fn configurations(&self) -> Vec<SoupConfig> {
+        let mut configs = Vec::new();
+        for &rule in &self.rules {
+            for &blue in &self.blue_probabilities {
+                for &red in &self.red_probabilities {
+                    let mut config = self.base.clone();
+                    config.grid.rule = rule;
+                    config.settings.blue_probability = blue;
+                    config.settings.red_probability = red;
+                    configs.push(config);
+                }
+            }
+        }
+        configs
+    }
+}
+
+/// Moyenne et variance (échantillon) d’une mesure
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct Summary {
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl Summary {
+    pub fn of(values: impl Iterator<Item = f64> + Clone) -> Summary {
+        let n = values.clone().count();
+        if n == 0 {
+            return Summary::default();
+        }
+        let mean = values.clone().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            values.map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Summary { mean, variance }
+    }
+}
+
+/// Résultats agrégés d’une configuration
+#[derive(Clone, Debug, Serialize)]
+pub struct SweepResult {
+    pub blue_probability: f32,
+    pub red_probability: f32,
+    pub rule: String,
+    pub runs: usize,
+    /// Part des soupes stabilisées avant la limite de générations
+    pub stabilized_fraction: f64,
+    pub total: Summary,
+    pub normal: Summary,
+    pub blue: Summary,
+    pub red: Summary,
+    /// Génération de stabilisation (soupes stabilisées uniquement)
+    pub stabilized_at: Summary,
+}
+
+impl SweepResult {
+    fn aggregate(config: &SoupConfig, runs: &[SoupResult]) -> SweepResult {
+        let stabilized = runs.iter().filter_map(|r| r.stabilized_at).map(|g| g as f64);
+        SweepResult {
+            blue_probability: config.settings.blue_probability,
+            red_probability: config.settings.red_probability,
+            rule: config.grid.rule.to_string(),
+            runs: runs.len(),
+            stabilized_fraction: stabilized.clone().count() as f64 / runs.len().max(1) as f64,
+            total: Summary::of(runs.iter().map(|r| r.total as f64)),
+            normal: Summary::of(runs.iter().map(|r| r.normal as f64)),
+            blue: Summary::of(runs.iter().map(|r| r.blue as f64)),
+            red: Summary::of(runs.iter().map(|r| r.red as f64)),
+            stabilized_at: Summary::of(stabilized),
+        }
+    }
+}
+
+/// Exécute toutes les (configuration, graine) en parallèle puis agrège par configuration
+pub fn run_sweep(spec: &SweepSpec) -> Vec<SweepResult> {
+    let seeds = spec.first_seed..spec.first_seed + spec.seeds;
+    spec.configurations()
+        .par_iter()
+        .map(|config| {
+            let runs: Vec<SoupResult> = seeds.clone().into_par_iter().map(|seed| run_soup(config, seed)).collect();
+            SweepResult::aggregate(config, &runs)
+        })
+        .collect()
+}
+
+pub fn write_csv(results: &[SweepResult], out: &mut impl Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "blue_probability,red_probability,rule,runs,stabilized_fraction,\
+         total_mean,total_var,normal_mean,normal_var,blue_mean,blue_var,red_mean,red_var,\
+         stabilized_at_mean,stabilized_at_var"
+    )?;
+    for r in results {
+        write!(out, "{},{},{},{},{}", r.blue_probability, r.red_probability, r.rule, r.runs, r.stabilized_fraction)?;
+        for s in [r.total, r.normal, r.blue, r.red, r.stabilized_at] {
+            write!(out, ",{},{}", s.mean, s.variance)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+pub fn write_json(results: &[SweepResult], out: &mut impl Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, results).map_err(io::Error::other)?;
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_value_and_range() {
+        let single: ValueRange = "0.25".parse().unwrap();
+        assert_eq!(single.values(), vec![0.25]);
+        let range: ValueRange = "0:0.3:0.05".parse().unwrap();
+        assert_eq!(range.values(), vec![0.0, 0.05, 0.1, 0.15, 0.2, 0.25, 0.3]);
+        for invalid in ["", "0:1", "a:b:c", "0:1:0.1:2", "NaN", "0:inf:0.1", "0:1:NaN", "0.1:0.3:0", "0:1:-0.1"] {
+            assert!(invalid.parse::<ValueRange>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn degenerate_ranges_give_their_start() {
+        assert_eq!("0.2:0.1:0.05".parse::<ValueRange>().unwrap().values(), vec![0.2]);
+        // la fin n’est pas dépassée si le pas ne tombe pas juste
+        assert_eq!("0:0.25:0.1".parse::<ValueRange>().unwrap().values(), vec![0.0, 0.1, 0.2]);
+    }
+
+    #[test]
+    fn long_ranges_are_rejected() {
+        assert_eq!("0:0.999:0.001".parse::<ValueRange>().unwrap().values().len(), MAX_VALUES);
+        assert!(matches!("0:1:0.000001".parse::<ValueRange>(), Err(RangeParseError::TooManyValues(_))));
+    }
+
+    #[test]
+    fn summary_uses_sample_variance() {
+        let summary = Summary::of([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].into_iter());
+        assert_eq!(summary.mean, 5.0);
+        assert!((summary.variance - 32.0 / 7.0).abs() < 1e-12);
+        assert_eq!(Summary::of([3.0].into_iter()).variance, 0.0);
+        assert_eq!(Summary::of(std::iter::empty()).mean, 0.0);
+    }
+}
@@ -1,107 +1,158 @@
 use bevy::prelude::*;
 use crate::{
     cell::{
-        Cell
+        Cell, ColorMode
     },
-    mutation_setting::MutationSettings
+    grid::{Grid, GridConfig},
+    mutation_setting::MutationSettings,
+    pattern::Pattern,
+    shortcuts::Action,
+    stats::{GenerationStats, PopulationHistory},
+    theme::Theme
 }; // pour accéder au composant
-use std::collections::HashMap;
+use rand::rngs::StdRng;
 
 #[derive(Resource)]
 pub struct StepTimer{
     pub timer: Timer,
     pub speed: f32,
+    pub paused: bool,
 }
 
-const GRID_WIDTH: usize = 20;
-const GRID_HEIGHT: usize = 20;
-const CELL_SIZE: f32 = 20.0;
+impl StepTimer {
+    /// Durée d’une étape, en secondes
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+        self.timer.set_duration(std::time::Duration::from_secs_f32(speed));
+    }
+}
+
+/// Générateur aléatoire de la simulation (initialisé avec `--seed` pour des parties reproductibles)
+#[derive(Resource)]
+pub struct SimRng(pub StdRng);
+
+/// Motif passé avec `--pattern`, utilisé pour la toute première grille
+#[derive(Resource, Default)]
+pub struct InitialPattern(pub Option<Pattern>);
+
+/// Bordure sombre autour de chaque cellule (touche G)
+#[derive(Resource)]
+pub struct GridLines(pub bool);
+
+impl Default for GridLines {
+    fn default() -> Self {
+        GridLines(true)
+    }
+}
+
+pub const CELL_SIZE: f32 = 20.0;
 pub const DEFAULT_SPEED: f32 = 0.05;
 pub const MIN_SPEED: f32 = 1.0;
 pub const MAX_SPEED: f32 = 0.05;
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_cells(
     time: Res<Time>,
     mut timer: ResMut<StepTimer>,
-    mut query: Query<(&mut Cell, &mut Sprite)>,
-    settings: Res<MutationSettings>
+    mut actions: EventReader<Action>,
+    mut query: Query<&mut Cell>,
+    settings: Res<MutationSettings>,
+    config: Res<GridConfig>,
+    mut rng: ResMut<SimRng>,
+    mut history: ResMut<PopulationHistory>,
 ) {
-    // 🔹 On fait avancer le timer à chaque frame
-    if !timer.timer.tick(time.delta()).just_finished() {
+    // 🔹 On fait avancer le timer à chaque frame (sauf en pause) ; « pas à pas » avance d’une génération
+    let step = actions.read().any(|a| *a == Action::Step);
+    if !step && (timer.paused || !timer.timer.tick(time.delta()).just_finished()) {
         return; // ⛔ On ne fait rien tant que le timer n’a pas fini
     }
 
-    // 1️⃣ Construire une map (x,y) -> alive
-    let mut grid_map: HashMap<(usize, usize), bool> = HashMap::new();
-    for (cell, _sprite) in query.iter() {
-        grid_map.insert((cell.x, cell.y), cell.alive);
-    }
-
-// 2️⃣ Calculer le prochain état pour chaque cellule
-    let mut updates: Vec<((usize, usize), bool)> = Vec::new();
-    for (cell, _sprite) in query.iter() {
-        let mut alive_neighbors = 0;
-
-        for dy in -1i32..=1 {
-            for dx in -1i32..=1 {
-                if dx == 0 && dy == 0 { continue; }
-                let nx = cell.x as i32 + dx;
-                let ny = cell.y as i32 + dy;
-                if nx >= 0 && nx < GRID_WIDTH as i32 && ny >= 0 && ny < GRID_HEIGHT as i32 {
-                    if *grid_map.get(&(nx as usize, ny as usize)).unwrap_or(&false) {
-                        alive_neighbors += 1;
-                    }
-                }
-            }
-        }
-
-        // Appliquer les règles classiques
+    // 1️⃣ Reconstruire la grille à partir des composants
+    let generation = history.latest().map_or(0, |s| s.generation);
+    let mut grid = Grid::from_cells(&config, generation, query.iter().copied());
 
-        updates.push(((cell.x, cell.y), cell.change_state(alive_neighbors)));
-    }
+    // 2️⃣ Calculer la génération suivante (mêmes règles que le mode batch)
+    let stats = grid.step(&settings, &mut rng.0);
 
-    // 3️⃣ Appliquer les changements aux cellules et aux sprites
-    for ((x, y), next_state) in updates {
-        for (mut cell, mut sprite) in query.iter_mut() {
-            if cell.x == x && cell.y == y {
+    // 3️⃣ Appliquer les changements aux cellules (les sprites suivent dans `color_cells`)
+    apply_grid(&grid, &mut query);
 
-                // mutation seulement si la cellule "renaît"
-                if !cell.alive && next_state {
-                    cell.random_mutation(&settings);
-                }
+    // 4️⃣ Enregistrer les statistiques de la génération
+    history.push(stats);
+}
 
-                cell.alive = next_state;
-                sprite.color = cell.cell_color(); // couleur cohérente
-                break;
-            }
+/// Recopie l’état de `grid` (mêmes dimensions) dans les cellules qui ont changé
+pub fn apply_grid(grid: &Grid, query: &mut Query<&mut Cell>) {
+    for mut cell in query.iter_mut() {
+        if let Some(next) = grid.get(cell.x, cell.y)
+            && next != &*cell
+        {
+            *cell = *next;
         }
     }
 }
 
-pub fn set_grid(commands: &mut Commands,settings: &Res<MutationSettings>) {
+// Cellules jointives quand les lignes de la grille sont masquées
+pub fn update_grid_lines(lines: Res<GridLines>, mut query: Query<(Ref<Cell>, &mut Sprite)>) {
+    let size = Vec2::splat(if lines.0 { CELL_SIZE - 1.0 } else { CELL_SIZE });
+    for (cell, mut sprite) in &mut query {
+        if (lines.is_changed() || cell.is_added()) && sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
+    }
+}
 
-    for y in 0..GRID_HEIGHT {
-        for x in 0..GRID_WIDTH {
-            let cell = Cell::new(x,y,settings);
+// 🎨 Couleur des sprites : cellules modifiées, ou toutes quand le mode change
+pub fn color_cells(mode: Res<ColorMode>, theme: Res<Theme>, mut query: Query<(Ref<Cell>, &mut Sprite)>) {
+    for (cell, mut sprite) in &mut query {
+        if mode.is_changed() || theme.is_changed() || cell.is_changed() {
+            sprite.color = cell.display_color(*mode, &theme);
+        }
+    }
+}
 
+/// Remplace toutes les cellules, dimensions comprises ; l’historique repart de la génération de `grid`
+pub fn replace_grid(commands: &mut Commands, old_cells: impl IntoIterator<Item = Entity>, grid: &Grid, history: &mut PopulationHistory) {
+    for e in old_cells {
+        commands.entity(e).despawn();
+    }
+    history.clear();
+    history.push(spawn_grid(commands, grid));
+}
 
-            let pos_x = (x as f32 - GRID_WIDTH as f32 / 2.0) * CELL_SIZE;
-            let pos_y = (y as f32 - GRID_HEIGHT as f32 / 2.0) * CELL_SIZE;
+/// Crée une entité par cellule de la grille et renvoie ses statistiques
+pub fn spawn_grid(commands: &mut Commands, grid: &Grid) -> GenerationStats {
+    for cell in &grid.cells {
+        let pos = cell_to_world(grid.width, grid.height, cell.x, cell.y);
 
-            commands
-                .spawn(SpriteBundle {
-                    sprite: Sprite {
-                        color: cell.cell_color(),
-                        custom_size: Some(Vec2::splat(CELL_SIZE - 1.0)),
-                        ..Default::default()
-                    },
-                    transform: Transform::from_xyz(pos_x, pos_y, 0.0),
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::NONE, // couleur posée par `color_cells`
+                    custom_size: Some(Vec2::splat(CELL_SIZE - 1.0)),
                     ..Default::default()
-                })
-                .insert(cell);
-        }
+                },
+                transform: Transform::from_translation(pos.extend(0.0)),
+                ..Default::default()
+            })
+            .insert(*cell);
     }
-}
 
+    grid.stats()
+}
 
+/// Centre d’une cellule dans le monde : grille centrée sur l’origine, ligne 0 en haut
+pub fn cell_to_world(width: usize, height: usize, x: usize, y: usize) -> Vec2 {
+    Vec2::new(
+        (x as f32 + 0.5 - width as f32 / 2.0) * CELL_SIZE,
+        (height as f32 / 2.0 - y as f32 - 0.5) * CELL_SIZE,
+    )
+}
 
+/// Cellule sous une position du monde (peut être hors de la grille)
+pub fn world_to_cell(width: usize, height: usize, pos: Vec2) -> (i64, i64) {
+    (
+        (pos.x / CELL_SIZE + width as f32 / 2.0).floor() as i64,
+        (height as f32 / 2.0 - pos.y / CELL_SIZE).floor() as i64,
+    )
+}
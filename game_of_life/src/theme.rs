@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use crate::cell::MutationType;
+
+/// Thèmes fournis
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Palettes des souches fournies
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaletteName {
+    /// Vert, bleu, rouge
+    Classic,
+    /// Jaune, bleu, vermillon (Okabe–Ito) : lisible quel que soit le daltonisme
+    Colorblind,
+    /// Blanc, cyan, magenta, pour le thème sombre
+    HighContrast,
+}
+
+/// Couleurs des trois souches
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Palette {
+    #[serde(with = "hex")]
+    pub normal: Color,
+    #[serde(with = "hex")]
+    pub blue: Color,
+    #[serde(with = "hex")]
+    pub red: Color,
+}
+
+impl Palette {
+    pub const CLASSIC: Palette = Palette {
+        normal: Color::srgb(0.2, 0.8, 0.2), // 🟩 vert
+        blue: Color::srgb(0.2, 0.4, 1.0),   // 🔵 bleu
+        red: Color::srgb(1.0, 0.2, 0.2),    // 🔴 rouge
+    };
+    pub const COLORBLIND: Palette = Palette {
+        normal: Color::srgb(0.941, 0.894, 0.259),
+        blue: Color::srgb(0.0, 0.447, 0.698),
+        red: Color::srgb(0.835, 0.369, 0.0),
+    };
+    pub const HIGH_CONTRAST: Palette = Palette {
+        normal: Color::srgb(1.0, 1.0, 1.0),
+        blue: Color::srgb(0.0, 0.9, 1.0),
+        red: Color::srgb(1.0, 0.0, 1.0),
+    };
+
+    pub fn named(name: PaletteName) -> Palette {
+        match name {
+            PaletteName::Classic => Palette::CLASSIC,
+            PaletteName::Colorblind => Palette::COLORBLIND,
+            PaletteName::HighContrast => Palette::HIGH_CONTRAST,
+        }
+    }
+
+    /// Couleur associée à la souche
+    pub fn strain(&self, mutation: MutationType) -> Color {
+        match mutation {
+            MutationType::None => self.normal,
+            MutationType::Blue => self.blue,
+            MutationType::Red => self.red,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::CLASSIC
+    }
+}
+
+/// Couleurs de la grille et de l’interface
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiColors {
+    /// Fond de la fenêtre, visible entre les cellules
+    #[serde(with = "hex")]
+    pub background: Color,
+    #[serde(with = "hex")]
+    pub dead_cell: Color,
+    #[serde(with = "hex")]
+    pub panel: Color,
+    /// Panneaux flottants (bibliothèque)
+    #[serde(with = "hex")]
+    pub overlay: Color,
+    #[serde(with = "hex")]
+    pub button: Color,
+    /// Option activée
+    #[serde(with = "hex")]
+    pub button_active: Color,
+    /// Enregistrement en cours
+    #[serde(with = "hex")]
+    pub button_alert: Color,
+    /// Bouton principal (« Nouvelle Grille ») au repos, survolé, enfoncé
+    #[serde(with = "hex")]
+    pub accent: Color,
+    #[serde(with = "hex")]
+    pub accent_hovered: Color,
+    #[serde(with = "hex")]
+    pub accent_pressed: Color,
+    #[serde(with = "hex")]
+    pub text: Color,
+    #[serde(with = "hex")]
+    pub text_dim: Color,
+    #[serde(with = "hex")]
+    pub graph_background: Color,
+    #[serde(with = "hex")]
+    pub graph_border: Color,
+    /// Lignes de la bibliothèque
+    #[serde(with = "hex")]
+    pub entry: Color,
+    #[serde(with = "hex")]
+    pub toast: Color,
+    #[serde(with = "hex")]
+    pub toast_error: Color,
+    #[serde(with = "hex")]
+    pub selection: Color,
+    #[serde(with = "hex")]
+    pub paste_preview: Color,
+}
+
+impl UiColors {
+    pub const DARK: UiColors = UiColors {
+        background: Color::srgb(0.169, 0.173, 0.184),
+        dead_cell: Color::BLACK,
+        panel: Color::srgb(0.1, 0.1, 0.1),
+        overlay: Color::srgba(0.1, 0.1, 0.1, 0.95),
+        button: Color::srgb(0.3, 0.3, 0.3),
+        button_active: Color::srgb(0.8, 0.7, 0.2),
+        button_alert: Color::srgb(0.7, 0.15, 0.15),
+        accent: Color::srgb(0.3, 0.6, 0.3),
+        accent_hovered: Color::srgb(0.35, 0.7, 0.35),
+        accent_pressed: Color::srgb(0.4, 0.8, 0.4),
+        text: Color::WHITE,
+        text_dim: Color::srgb(0.6, 0.6, 0.6),
+        graph_background: Color::srgb(0.05, 0.05, 0.05),
+        graph_border: Color::srgb(0.4, 0.4, 0.4),
+        entry: Color::srgb(0.2, 0.2, 0.2),
+        toast: Color::srgba(0.15, 0.15, 0.15, 0.9),
+        toast_error: Color::srgba(0.6, 0.1, 0.1, 0.9),
+        selection: Color::srgba(1.0, 1.0, 1.0, 0.15),
+        paste_preview: Color::srgba(1.0, 1.0, 0.2, 0.12),
+    };
+    pub const LIGHT: UiColors = UiColors {
+        background: Color::srgb(0.75, 0.75, 0.77),
+        dead_cell: Color::srgb(0.97, 0.97, 0.97),
+        panel: Color::srgb(0.92, 0.92, 0.93),
+        overlay: Color::srgba(0.97, 0.97, 0.97, 0.95),
+        button: Color::srgb(0.78, 0.78, 0.8),
+        button_active: Color::srgb(0.95, 0.78, 0.3),
+        button_alert: Color::srgb(0.9, 0.35, 0.35),
+        accent: Color::srgb(0.45, 0.75, 0.45),
+        accent_hovered: Color::srgb(0.5, 0.82, 0.5),
+        accent_pressed: Color::srgb(0.55, 0.9, 0.55),
+        text: Color::srgb(0.1, 0.1, 0.1),
+        text_dim: Color::srgb(0.4, 0.4, 0.4),
+        graph_background: Color::WHITE,
+        graph_border: Color::srgb(0.6, 0.6, 0.6),
+        entry: Color::srgb(0.85, 0.85, 0.87),
+        toast: Color::srgba(0.85, 0.85, 0.87, 0.95),
+        toast_error: Color::srgba(0.95, 0.55, 0.55, 0.95),
+        selection: Color::srgba(0.0, 0.0, 0.0, 0.12),
+        paste_preview: Color::srgba(0.9, 0.6, 0.0, 0.15),
+    };
+}
+
+impl Default for UiColors {
+    fn default() -> Self {
+        UiColors::DARK
+    }
+}
+
+/// Toutes les couleurs de l’application : grille, souches et interface
+#[derive(Resource, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub palette: Palette,
+    pub ui: UiColors,
+}
+
+impl Theme {
+    pub fn named(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Dark => Theme { palette: Palette::CLASSIC, ui: UiColors::DARK },
+            ThemeName::Light => Theme { palette: Palette::CLASSIC, ui: UiColors::LIGHT },
+        }
+    }
+
+    /// Fichier JSON dont les couleurs (« #rrggbb » ou « #rrggbbaa ») remplacent celles de `base` ;
+    /// les couleurs absentes gardent leur valeur
+    pub fn load(path: &Path, base: Theme) -> io::Result<Theme> {
+        let text = fs::read_to_string(path)?;
+        let overrides: Value = serde_json::from_str(&text).map_err(io::Error::other)?;
+        let mut merged = serde_json::to_value(base).map_err(io::Error::other)?;
+        merge(&mut merged, overrides);
+        serde_json::from_value(merged).map_err(io::Error::other)
+    }
+
+    /// Couleur d’une souche
+    pub fn strain(&self, mutation: MutationType) -> Color {
+        self.palette.strain(mutation)
+    }
+}
+
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                merge(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+/// Options d’apparence communes à l’application et au rendu d’images
+#[derive(Args, Clone)]
+pub struct ThemeArgs {
+    /// Thème de base
+    #[arg(long, value_enum, default_value_t = ThemeName::Dark)]
+    pub theme: ThemeName,
+    /// Couleurs des souches (remplace celles du thème)
+    #[arg(long, value_enum)]
+    pub palette: Option<PaletteName>,
+    /// Fichier JSON de couleurs qui complète le thème de base
+    #[arg(long)]
+    pub theme_file: Option<PathBuf>,
+}
+
+impl ThemeArgs {
+    pub fn theme(&self) -> io::Result<Theme> {
+        let mut theme = Theme::named(self.theme);
+        if let Some(path) = &self.theme_file {
+            theme = Theme::load(path, theme)?;
+        }
+        if let Some(palette) = self.palette {
+            theme.palette = Palette::named(palette);
+        }
+        Ok(theme)
+    }
+}
+
+/// (Dé)sérialisation d’une couleur en « #rrggbb » (ou « #rrggbbaa » si elle est transparente)
+mod hex {
+    use bevy::prelude::*;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_srgba().to_hex().to_lowercase())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Srgba::hex(&text)
+            .map(Color::from)
+            .map_err(|e| D::Error::custom(format!("couleur « {text} » invalide : {e}")))
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use crate::theme::Theme;
+
+/// Durée d’affichage d’une notification, en secondes
+const TOAST_SECONDS: f32 = 4.0;
+
+/// Notification affichée quelques secondes en bas à droite de la grille
+#[derive(Event, Clone, Debug)]
+pub struct Toast {
+    pub message: String,
+    pub error: bool,
+}
+
+impl Toast {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self { message: message.into(), error: false }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self { message: message.into(), error: true }
+    }
+}
+
+#[derive(Component)]
+pub struct ToastContainer;
+
+#[derive(Component)]
+pub struct ToastTimer(Timer);
+
+pub fn show_toasts(
+    mut commands: Commands,
+    mut events: EventReader<Toast>,
+    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    container_query: Query<Entity, With<ToastContainer>>,
+) {
+    let Ok(container) = container_query.get_single() else {
+        return;
+    };
+
+    for toast in events.read() {
+        if toast.error {
+            warn!("{}", toast.message);
+        } else {
+            info!("{}", toast.message);
+        }
+
+        let background = if toast.error { theme.ui.toast_error } else { theme.ui.toast };
+        commands.entity(container).with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            max_width: Val::Px(420.0),
+                            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                            ..default()
+                        },
+                        background_color: background.into(),
+                        ..default()
+                    },
+                    ToastTimer(Timer::from_seconds(TOAST_SECONDS, TimerMode::Once)),
+                ))
+                .with_children(|toast_node| {
+                    toast_node.spawn(TextBundle::from_section(
+                        toast.message.clone(),
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: 16.0,
+                            color: theme.ui.text,
+                        },
+                    ));
+                });
+        });
+    }
+}
+
+pub fn expire_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut ToastTimer)>,
+) {
+    for (entity, mut timer) in &mut toasts {
+        if timer.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
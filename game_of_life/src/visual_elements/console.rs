@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::{
+    console::{ConsoleLog, ConsolePanel, ConsolePrompt},
+    theme::Theme,
+    widgets::{Focusable, FONT}
+};
+
+
+/*********************************** Console **********************************/
+
+// Bandeau déroulé en haut de la grille : journal, puis ligne de saisie ; cliquer dessus lui rend le clavier
+pub fn spawn_console(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme) {
+    let font = asset_server.load(FONT);
+    let style = TextStyle {
+        font,
+        font_size: 15.0,
+        color: theme.ui.text,
+    };
+
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.0),
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    height: Val::Percent(40.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                background_color: theme.ui.overlay.into(),
+                visibility: Visibility::Hidden,
+                // les clics sur la console n’atteignent pas la grille
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(6),
+                ..default()
+            },
+            ConsolePanel,
+            Interaction::default(),
+            Focusable,
+        ))
+        .with_children(|panel| {
+            // journal collé au bas de sa zone, au-dessus de la saisie ; écrit par `update_console`
+            panel
+                .spawn(NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        min_height: Val::Px(0.0),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::FlexEnd,
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|log| {
+                    log.spawn((TextBundle::from_section("", style.clone()), ConsoleLog));
+                });
+            panel.spawn((TextBundle::from_section("> ", style), ConsolePrompt));
+        });
+}
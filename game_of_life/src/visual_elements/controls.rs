@@ -1,46 +1,89 @@
 use bevy::prelude::*;
 use crate::{
     interface::{
-        SetWindowButton,SpeedSlider,SliderHandle,SpeedText,
-        BAR_WIDTH,HANDLE_WIDTH
+        SetWindowButton,PauseButton,SpeedSlider,RecordButton,StabilizationText,CensusButton,CensusText,LibraryButton,SaveSessionButton
     },
-    mutation_setting::{MutationCheckbox},
-    systems::{MIN_SPEED,MAX_SPEED,DEFAULT_SPEED}
+    mutation_setting::{MutationCheckbox,MutationSettings},
+    detection::StabilizationCheckbox,
+    camera::ViewButton,
+    cell::{ColorMode, ColorModeButton},
+    draw::DrawControl,
+    export::{AnimationFramesInput, ExportButton, ExportSettings},
+    generator::{Generator, GeneratorControl, GeneratorKind, Symmetry, MAX_REGIONS},
+    heatmap::{HeatmapButton, HeatmapMode},
+    selection::{SelectionAction, SelectionButton},
+    settings::ResetSettingsButton,
+    systems::{MIN_SPEED,MAX_SPEED},
+    theme::Theme,
+    locale::{Language, LanguageSelector},
+    widgets::{
+        button::{spawn_button, spawn_button_row, ButtonLook, ButtonSize},
+        dropdown::{spawn_dropdown, Dropdown},
+        number::{spawn_number_input, NumberInput},
+        slider::{spawn_slider, Slider},
+        toggle::{spawn_toggle, Toggle}
+    }
 };
 
 
+/************************************ Language ************************************/
+
+////////////////
+//  Dropdown  //
+////////////////
+
+// Option choisie synchronisée par `language_selector_system`
+pub fn spawn_language_selector(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    let dropdown = Dropdown::new(Language::ALL.map(Language::message), 0);
+    spawn_dropdown(ui, font, theme, ButtonSize::MEDIUM, dropdown, LanguageSelector);
+}
+
+
 /************************************ New Grid ************************************/
 
 //////////////
 //  Button  //
-////////////// 
-
-pub fn spawn_new_grid_button(ui: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
-    ui.spawn((
-        ButtonBundle {
-            style: Style {
-                width: Val::Px(160.0),
-                height: Val::Px(50.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(10.0)),
-                ..default()
-            },
-            background_color: Color::srgb(0.3, 0.6, 0.3).into(),
-            ..default()
-        },
-        SetWindowButton,
-    ))
-    .with_children(|b| {
-        b.spawn(TextBundle::from_section(
-            "Nouvelle Grille",
-            TextStyle {
-                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 22.0,
-                color: Color::WHITE,
-            },
-        ));
-    });
+//////////////
+
+pub fn spawn_new_grid_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::LARGE, ButtonLook::Accent, "new-grid", SetWindowButton);
+}
+
+//////////////////
+//  Generator   //
+//////////////////
+
+// Réglages du bouton « Nouvelle Grille », resynchronisés par `generator_control_system`
+pub fn spawn_generator_controls(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, generator: &Generator) {
+    let kind = GeneratorKind::ALL.iter().position(|k| *k == generator.kind).unwrap_or_default();
+    spawn_dropdown(ui, font, theme, ButtonSize::MEDIUM, Dropdown::new(GeneratorKind::ALL.map(GeneratorKind::message), kind), GeneratorControl::Kind);
+
+    let density = Slider { step: 0.05, label: "generator-density", ..Slider::new(0.05, 0.95, generator.density) };
+    spawn_slider(ui, font, theme, density, GeneratorControl::Density);
+
+    let soup = NumberInput::new(2.0, 256.0, 2.0, generator.soup_size as f32);
+    spawn_number_input(ui, font, theme, "generator-soup-size", soup, GeneratorControl::SoupSize);
+
+    let symmetry = Symmetry::ALL.iter().position(|s| *s == generator.symmetry).unwrap_or_default();
+    spawn_dropdown(ui, font, theme, ButtonSize::MEDIUM, Dropdown::new(Symmetry::ALL.map(Symmetry::message), symmetry), GeneratorControl::Symmetry);
+
+    let scale = NumberInput::new(2.0, 64.0, 1.0, generator.noise_scale);
+    spawn_number_input(ui, font, theme, "generator-noise-scale", scale, GeneratorControl::NoiseScale);
+
+    let regions = NumberInput::new(1.0, MAX_REGIONS as f32, 1.0, generator.regions as f32);
+    spawn_number_input(ui, font, theme, "generator-regions-count", regions, GeneratorControl::Regions);
+}
+
+
+/************************************ Pause ************************************/
+
+//////////////
+//  Button  //
+//////////////
+
+// Libellé « Lecture » / « Pause » choisi par `pause_button_system`
+pub fn spawn_pause_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::WIDE, ButtonLook::Normal, "pause", PauseButton);
 }
 
 
@@ -48,101 +91,185 @@ pub fn spawn_new_grid_button(ui: &mut ChildBuilder, asset_server: &Res<AssetServ
 
 ///////////////
 //  Control  //
-/////////////// 
+///////////////
+
+pub fn spawn_speed_control(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, speed: f32) {
+    // lent à gauche, rapide à droite
+    let slider = Slider { step: 0.05, label: "speed", ..Slider::new(MIN_SPEED, MAX_SPEED, speed) };
+    spawn_slider(ui, font, theme, slider, SpeedSlider);
+}
+
+
+/************************************ View ************************************/
+
+///////////////
+//  Buttons  //
+///////////////
+
+pub fn spawn_view_buttons(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button_row(ui, font, theme, 77.0, [("view-fit", ViewButton::FitPattern), ("view-reset", ViewButton::Reset)]);
+}
+
+/////////////
+//  Color  //
+/////////////
+
+pub fn spawn_color_mode_buttons(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button_row(ui, font, theme, 62.0, [
+        ("color-strain", ColorModeButton(ColorMode::Strain)),
+        ("color-age", ColorModeButton(ColorMode::Age)),
+        ("color-trail", ColorModeButton(ColorMode::Trail)),
+    ]);
+}
+
+
+/************************************ Heatmap ************************************/
+
+///////////////
+//  Buttons  //
+///////////////
+
+pub fn spawn_heatmap_buttons(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button_row(ui, font, theme, 62.0, [
+        ("heatmap-changes", HeatmapButton::Mode(HeatmapMode::Changes)),
+        ("heatmap-presence", HeatmapButton::Mode(HeatmapMode::Presence)),
+        ("heatmap-strains", HeatmapButton::Mode(HeatmapMode::Strains)),
+    ]);
+    spawn_button_row(ui, font, theme, 62.0, [
+        ("heatmap-opaque", HeatmapButton::Opaque),
+        ("heatmap-clear", HeatmapButton::Clear),
+        ("heatmap-export", HeatmapButton::Export),
+    ]);
+}
+
+
+/************************************ Selection ************************************/
+
+///////////////
+//  Buttons  //
+///////////////
+
+pub fn spawn_selection_buttons(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    let rows = [
+        [("selection-copy", SelectionAction::Copy), ("selection-cut", SelectionAction::Cut), ("selection-paste", SelectionAction::Paste)],
+        [("selection-rotate", SelectionAction::RotateClockwise), ("selection-flip-h", SelectionAction::FlipHorizontal), ("selection-flip-v", SelectionAction::FlipVertical)],
+        [("selection-fill", SelectionAction::Fill), ("selection-clear", SelectionAction::Clear), ("selection-randomize", SelectionAction::Randomize)],
+    ];
+    for row in rows {
+        spawn_button_row(ui, font, theme, 62.0, row.map(|(message, action)| (message, SelectionButton(action))));
+    }
+}
+
+
+//////////////
+//   Draw   //
+//////////////
+
+// Mode dessin et symétrie des traits, resynchronisés par `draw_control_system`
+pub fn spawn_draw_controls(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_toggle(ui, font, theme, "draw-mode", Toggle::default(), DrawControl::Enabled);
+    let symmetry = Symmetry::ALL.iter().position(|s| *s == Symmetry::C1).unwrap_or_default();
+    spawn_dropdown(ui, font, theme, ButtonSize::MEDIUM, Dropdown::new(Symmetry::ALL.map(Symmetry::message), symmetry), DrawControl::Symmetry);
+}
+
+
+/************************************ Library ************************************/
+
+//////////////
+//  Button  //
+//////////////
+
+pub fn spawn_library_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::MEDIUM, ButtonLook::Normal, "library", LibraryButton);
+}
 
-pub fn spawn_speed_control(ui: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
-    let ratio = (MIN_SPEED - DEFAULT_SPEED) / (MIN_SPEED - MAX_SPEED);
 
+/************************************ Mutation ************************************/
+
+//////////////
+// CHECKBOX //
+//////////////
+
+// Coches aux couleurs des souches, resynchronisées par `update_mutation_checkboxes`
+pub fn spawn_mutation_checkboxes(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, settings: &MutationSettings) {
     ui.spawn(NodeBundle {
         style: Style {
-            width: Val::Px(200.0),
-            height: Val::Px(30.0),
-            flex_direction: FlexDirection::Row,
-            align_items: AlignItems::Center,
-            justify_content: JustifyContent::SpaceBetween,
+            flex_direction: FlexDirection::Column,
             margin: UiRect::all(Val::Px(10.0)),
             ..default()
         },
-        background_color: Color::NONE.into(),
         ..default()
     })
-    .with_children(|parent| {
-        spawn_speed_bar(parent, ratio);
-        spawn_speed_text(parent, asset_server);
+    .with_children(|col| {
+        let blue = Toggle { checked: settings.allow_blue, color: Some(theme.palette.blue) };
+        let red = Toggle { checked: settings.allow_red, color: Some(theme.palette.red) };
+        spawn_toggle(col, font, theme, "mutation-blue", blue, MutationCheckbox::Blue);
+        spawn_toggle(col, font, theme, "mutation-red", red, MutationCheckbox::Red);
     });
 }
 
-/////////////
-//// Bar ////
-///////////// 
-
-fn spawn_speed_bar(parent: &mut ChildBuilder, ratio: f32) {
-    parent
-        .spawn((
-            NodeBundle {
-                style: Style {
-                    width: Val::Px(BAR_WIDTH),
-                    height: Val::Px(20.0),
-                    position_type: PositionType::Relative,
-                    justify_content: JustifyContent::FlexStart,
-                    align_items: AlignItems::Center,
-                    border: UiRect::all(Val::Px(2.0)),
-                    ..default()
-                },
-                background_color: Color::srgb(0.3, 0.3, 0.3).into(),
-                border_color: BorderColor(Color::WHITE),
-                ..default()
-            },
-            SpeedSlider,
-            Interaction::default(),
-        ))
-        .with_children(|bar| {
-            let handle_x = BAR_WIDTH - (HANDLE_WIDTH * 2.0 + 2.0);
-            bar.spawn((
-                NodeBundle {
-                    style: Style {
-                        width: Val::Px(HANDLE_WIDTH),
-                        height: Val::Px(24.0),
-                        left: Val::Px(handle_x),
-                        position_type: PositionType::Absolute,
-                        ..default()
-                    },
-                    background_color: Color::srgb(1.0 - ratio, 1.0, 0.2).into(),
-                    ..default()
-                },
-                SliderHandle,
-            ));
-        });
+
+/************************************ Record ************************************/
+
+//////////////
+//  Button  //
+//////////////
+
+// Libellé et couleur suivent l’enregistrement (voir `record_button_system`)
+pub fn spawn_record_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::WIDE, ButtonLook::Normal, "record-start", RecordButton);
+}
+
+
+/************************************ Export ************************************/
+
+///////////////
+//  Buttons  //
+///////////////
+
+pub fn spawn_export_buttons(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, settings: &ExportSettings) {
+    spawn_button_row(ui, font, theme, 62.0, [
+        ("export-image", ExportButton::Image),
+        ("export-animate", ExportButton::Animation),
+        // libellé remplacé par le format choisi (voir `update_export_buttons`)
+        ("export-format", ExportButton::Format),
+    ]);
+    // nombre de générations capturées par « Animer »
+    let frames = NumberInput::new(1.0, 1000.0, 10.0, settings.frames as f32);
+    spawn_number_input(ui, font, theme, "export-frames", frames, AnimationFramesInput);
 }
 
+
+/************************************ Session ************************************/
+
+//////////////
+//  Button  //
 //////////////
-//// Text ////
-////////////// 
 
-fn spawn_speed_text(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
-    parent.spawn((
-        TextBundle::from_section(
-            format!("{:.2} s/étape", DEFAULT_SPEED),
-            TextStyle {
-                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 18.0,
-                color: Color::WHITE,
-            },
-        ),
-        SpeedText,
-    ));
+pub fn spawn_save_session_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::MEDIUM, ButtonLook::Normal, "save-session", SaveSessionButton);
 }
 
-/************************************ Mutation ************************************/
+
+/************************************ Settings ************************************/
 
 //////////////
-// CHECKBOX //
-////////////// 
+//  Button  //
+//////////////
+
+// Les réglages eux-mêmes sont enregistrés à chaque changement (voir `save_settings_system`)
+pub fn spawn_reset_settings_button(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    spawn_button(ui, font, theme, ButtonSize::MEDIUM, ButtonLook::Normal, "settings-reset-button", ResetSettingsButton);
+}
 
 
-pub fn spawn_mutation_checkboxes(ui: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
-    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+/************************************ Stabilization ************************************/
 
+//////////////
+//  Status  //
+//////////////
+
+pub fn spawn_stabilization_controls(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
     ui.spawn(NodeBundle {
         style: Style {
             flex_direction: FlexDirection::Column,
@@ -152,46 +279,53 @@ pub fn spawn_mutation_checkboxes(ui: &mut ChildBuilder, asset_server: &Res<Asset
         ..default()
     })
     .with_children(|col| {
-        spawn_checkbox(col, font.clone(), "Mutation Bleue", MutationCheckbox::Blue,Color::srgb(0.2, 0.2, 0.8).into());
-        spawn_checkbox(col, font.clone(), "Mutation Rouge", MutationCheckbox::Red,Color::srgb(0.8, 0.2, 0.2).into());
+        col.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    color: theme.ui.text,
+                },
+            ),
+            StabilizationText,
+        ));
+        spawn_toggle(col, font, theme, "auto-pause", Toggle::default(), StabilizationCheckbox::AutoPause);
+        spawn_toggle(col, font, theme, "auto-reset", Toggle::default(), StabilizationCheckbox::AutoReset);
     });
 }
 
-fn spawn_checkbox(parent: &mut ChildBuilder, font: Handle<Font>, label: &str, kind: MutationCheckbox, bg_color: BackgroundColor) {
-    parent
-        .spawn(NodeBundle {
-            style: Style {
-                flex_direction: FlexDirection::Row,
-                align_items: AlignItems::Center,
-                margin: UiRect::all(Val::Px(5.0)),
-                ..default()
-            },
+
+/************************************ Census ************************************/
+
+//////////////
+//  Button  //
+//////////////
+
+pub fn spawn_census_panel(ui: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme) {
+    ui.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            margin: UiRect::all(Val::Px(10.0)),
             ..default()
-        })
-        .with_children(|row| {
-            // petit carré cliquable
-            row.spawn((
-                ButtonBundle {
-                    style: Style {
-                        width: Val::Px(24.0),
-                        height: Val::Px(24.0),
-                        margin: UiRect::right(Val::Px(10.0)),
-                        ..default()
-                    },
-                    background_color: bg_color,
-                    ..default()
-                },
-                kind,
-            ));
+        },
+        ..default()
+    })
+    .with_children(|col| {
+        spawn_button(col, font, theme, ButtonSize::MEDIUM, ButtonLook::Normal, "census", CensusButton);
 
-            // texte à côté
-            row.spawn(TextBundle::from_section(
-                label,
+        // liste des objets (remplie au premier recensement)
+        col.spawn((
+            TextBundle::from_section(
+                "",
                 TextStyle {
-                    font,
-                    font_size: 20.0,
-                    color: Color::WHITE,
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: theme.ui.text,
                 },
-            ));
-        });
+            ),
+            CensusText,
+        ));
+    });
 }
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use crate::{
+    cell::MutationType,
+    interface::{
+        GraphPoint,GraphSegment,GraphSeries,GraphLegend,GraphModeButton,
+        GRAPH_WIDTH,GRAPH_HEIGHT
+    },
+    stats::HISTORY_LEN,
+    theme::Theme,
+    widgets::button::{spawn_button, ButtonLook, ButtonSize}
+};
+
+
+/************************************ Population ************************************/
+
+///////////////
+//   Graph   //
+///////////////
+
+pub fn spawn_population_graph(ui: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    ui.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            margin: UiRect::all(Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    })
+    .with_children(|col| {
+        spawn_graph_legend(col, font.clone(), theme);
+        spawn_graph_area(col, theme);
+        spawn_graph_mode_button(col, font, theme);
+    });
+}
+
+////////////////
+//   Legend   //
+////////////////
+
+fn spawn_graph_legend(parent: &mut ChildBuilder, font: Handle<Font>, theme: &Theme) {
+    let style = |color: Color| TextStyle {
+        font: font.clone(),
+        font_size: 16.0,
+        color,
+    };
+
+    // une section par valeur : génération, total, puis une par souche (écrites par `update_population_graph`)
+    let mut sections = vec![
+        TextSection::new("", style(theme.ui.text)),
+        TextSection::new("", style(theme.ui.text)),
+    ];
+    for mutation in MutationType::ALL {
+        sections.push(TextSection::new("0  ", style(theme.strain(mutation))));
+    }
+
+    parent.spawn((
+        TextBundle::from_sections(sections).with_style(Style {
+            margin: UiRect::bottom(Val::Px(5.0)),
+            ..default()
+        }),
+        GraphLegend,
+    ));
+}
+
+//////////////
+//   Area   //
+//////////////
+
+fn spawn_graph_area(parent: &mut ChildBuilder, theme: &Theme) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(GRAPH_WIDTH),
+                height: Val::Px(GRAPH_HEIGHT),
+                flex_direction: FlexDirection::Row,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            background_color: theme.ui.graph_background.into(),
+            border_color: BorderColor(theme.ui.graph_border),
+            ..default()
+        })
+        .with_children(|area| {
+            for column in 0..HISTORY_LEN {
+                spawn_graph_column(area, theme, column);
+            }
+        });
+}
+
+// Une colonne = une génération : un point par courbe et un segment par souche (vue empilée)
+fn spawn_graph_column(parent: &mut ChildBuilder, theme: &Theme, column: usize) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0 / HISTORY_LEN as f32),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Relative,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|col| {
+            for mutation in MutationType::ALL {
+                col.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            position_type: PositionType::Absolute,
+                            display: Display::None,
+                            ..default()
+                        },
+                        background_color: theme.strain(mutation).into(),
+                        ..default()
+                    },
+                    GraphSegment { column, mutation },
+                ));
+            }
+
+            let series = std::iter::once(GraphSeries::Total)
+                .chain(MutationType::ALL.map(GraphSeries::Strain));
+            for series in series {
+                col.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(2.0),
+                            position_type: PositionType::Absolute,
+                            display: Display::None,
+                            ..default()
+                        },
+                        background_color: series.color(theme).into(),
+                        ..default()
+                    },
+                    GraphPoint { column, series },
+                ));
+            }
+        });
+}
+
+//////////////
+//  Button  //
+//////////////
+
+fn spawn_graph_mode_button(parent: &mut ChildBuilder, font: Handle<Font>, theme: &Theme) {
+    // libellé choisi par `graph_mode_button_system`
+    let size = ButtonSize { width: GRAPH_WIDTH, height: 30.0, margin: 5.0, font_size: 16.0 };
+    spawn_button(parent, &font, theme, size, ButtonLook::Normal, "graph-lines", GraphModeButton);
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::{
+    interface::{HelpContent, HelpOverlay},
+    shortcuts::{default_keys_path, Action, KeyBindings},
+    theme::Theme,
+    locale::{Localization, Localized}
+};
+
+const KEYS_WIDTH: f32 = 170.0;
+const ACTION_WIDTH: f32 = 190.0;
+
+
+/************************************ Help ************************************/
+
+///////////////
+//   Panel   //
+///////////////
+
+// Panneau au-dessus de la grille, affiché et masqué par la touche d’aide
+pub fn spawn_help_overlay(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(5.0),
+                    left: Val::Percent(5.0),
+                    right: Val::Percent(5.0),
+                    bottom: Val::Percent(5.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(12.0)),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                background_color: theme.ui.overlay.into(),
+                visibility: Visibility::Hidden,
+                // les clics sur le panneau n’atteignent pas la grille
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(5),
+                ..default()
+            },
+            HelpOverlay,
+            Interaction::default(),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font,
+                        font_size: 20.0,
+                        color: theme.ui.text,
+                    },
+                ),
+                Localized("help-title"),
+            ));
+
+            // rempli par `update_help_overlay`
+            panel.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        min_height: Val::Px(0.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                HelpContent,
+            ));
+        });
+}
+
+///////////////
+//  Entries  //
+///////////////
+
+// Où modifier les touches, puis une ligne « touches — action » par action, en colonnes
+pub fn spawn_help_entries(content: &mut ChildBuilder, font: Handle<Font>, theme: &Theme, loc: &Localization, bindings: &KeyBindings) {
+    let style = |color: Color| TextStyle { font: font.clone(), font_size: 15.0, color };
+
+    if let Some(path) = default_keys_path() {
+        content.spawn(TextBundle::from_section(
+            loc.t_with("help-hint", &[("file", path.display().to_string().into())]),
+            TextStyle { font_size: 13.0, ..style(theme.ui.text_dim) },
+        ));
+    }
+
+    content
+        .spawn(NodeBundle {
+            style: Style {
+                flex_grow: 1.0,
+                min_height: Val::Px(0.0),
+                flex_direction: FlexDirection::Column,
+                flex_wrap: FlexWrap::Wrap,
+                align_content: AlignContent::FlexStart,
+                column_gap: Val::Px(24.0),
+                row_gap: Val::Px(3.0),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|columns| {
+            for action in Action::ALL {
+                let keys: Vec<String> = bindings.keys(action).iter().map(ToString::to_string).collect();
+                columns
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(TextBundle::from_section(keys.join(", "), style(theme.ui.button_active)).with_style(Style {
+                            width: Val::Px(KEYS_WIDTH),
+                            ..default()
+                        }));
+                        row.spawn(TextBundle::from_section(loc.t(&action.message()), style(theme.ui.text)).with_style(Style {
+                            width: Val::Px(ACTION_WIDTH),
+                            ..default()
+                        }));
+                    });
+            }
+        });
+}
@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use crate::{
+    interface::{LibraryContent, LibraryEntryButton, LibraryFilter, LibraryList, LibraryPanel},
+    library::{PatternLibrary, THUMBNAIL_SIZE},
+    theme::Theme,
+    locale::{Localization, Localized},
+    widgets::text_field::{spawn_text_field, TextField}
+};
+
+
+/************************************ Library ************************************/
+
+///////////////
+//   Panel   //
+///////////////
+
+// Fenêtre flottante au-dessus de la grille, masquée jusqu’au clic sur « Bibliothèque »
+pub fn spawn_library_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    left: Val::Px(10.0),
+                    width: Val::Px(280.0),
+                    height: Val::Percent(80.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..default()
+                },
+                background_color: theme.ui.overlay.into(),
+                visibility: Visibility::Hidden,
+                // les clics sur le panneau n’atteignent pas la grille
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            LibraryPanel,
+            Interaction::default(),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color: theme.ui.text,
+                    },
+                ),
+                Localized("library-title"),
+            ));
+            panel.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 13.0,
+                        color: theme.ui.text_dim,
+                    },
+                ),
+                Localized("library-hint"),
+            ));
+
+            // filtre par nom ou catégorie
+            let filter = TextField { placeholder: "library-filter", max_len: 32, ..default() };
+            spawn_text_field(panel, &font, theme, 264.0, filter, LibraryFilter);
+
+            // zone visible ; le contenu défile à la molette
+            panel
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_grow: 1.0,
+                            min_height: Val::Px(0.0),
+                            margin: UiRect::top(Val::Px(6.0)),
+                            overflow: Overflow::clip(),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    LibraryList,
+                    Interaction::default(),
+                ))
+                .with_children(|list| {
+                    list.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(4.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        LibraryContent,
+                    ));
+                });
+        });
+}
+
+///////////////
+//  Entries  //
+///////////////
+
+// Un titre par catégorie puis une ligne vignette + nom par motif ; seuls les motifs dont le nom
+// ou la catégorie contient `filter` (sans tenir compte de la casse) sont listés
+pub fn spawn_library_entries(content: &mut ChildBuilder, font: Handle<Font>, theme: &Theme, loc: &Localization, library: &PatternLibrary, filter: &str) {
+    let filter = filter.trim().to_lowercase();
+    let mut category = None;
+    for (i, entry) in library.entries.iter().enumerate() {
+        let matches = |text: &str| text.to_lowercase().contains(&filter);
+        if !matches(&entry.name) && !matches(&loc.category(&entry.category)) {
+            continue;
+        }
+        if category != Some(&entry.category) {
+            category = Some(&entry.category);
+            content.spawn(TextBundle::from_section(
+                loc.category(&entry.category).to_uppercase(),
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 14.0,
+                    color: theme.ui.text_dim,
+                },
+            ).with_style(Style {
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            }));
+        }
+
+        content
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(8.0),
+                        padding: UiRect::all(Val::Px(3.0)),
+                        ..default()
+                    },
+                    background_color: theme.ui.entry.into(),
+                    ..default()
+                },
+                LibraryEntryButton(i),
+            ))
+            .with_children(|row| {
+                row.spawn(ImageBundle {
+                    style: Style {
+                        width: Val::Px(THUMBNAIL_SIZE as f32),
+                        height: Val::Px(THUMBNAIL_SIZE as f32),
+                        ..default()
+                    },
+                    image: UiImage::new(entry.thumbnail.clone()),
+                    ..default()
+                });
+                row.spawn(TextBundle::from_section(
+                    format!("{}\n{} × {}", entry.name, entry.pattern.width, entry.pattern.height),
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 15.0,
+                        color: theme.ui.text,
+                    },
+                ));
+            });
+    }
+
+    if category.is_none() && !filter.is_empty() {
+        content.spawn(TextBundle::from_section(
+            loc.t("library-no-match"),
+            TextStyle {
+                font: font.clone(),
+                font_size: 14.0,
+                color: theme.ui.text_dim,
+            },
+        ).with_style(Style {
+            margin: UiRect::top(Val::Px(6.0)),
+            ..default()
+        }));
+    }
+
+    // où déposer ses propres fichiers
+    if let Some(dir) = library.user_dir() {
+        content.spawn(TextBundle::from_section(
+            loc.t_with("library-user-dir", &[("dir", dir.display().to_string().into())]),
+            TextStyle {
+                font,
+                font_size: 12.0,
+                color: theme.ui.text_dim,
+            },
+        ).with_style(Style {
+            margin: UiRect::top(Val::Px(8.0)),
+            ..default()
+        }));
+    }
+}
@@ -1,2 +1,6 @@
 pub mod panel;
-pub mod controls;
\ No newline at end of file
+pub mod controls;
+pub mod graph;
+pub mod library;
+pub mod help;
+pub mod console;
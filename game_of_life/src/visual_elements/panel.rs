@@ -1,13 +1,24 @@
 use bevy::prelude::*;
 use crate::{
-    visual_elements::controls::{spawn_new_grid_button,spawn_speed_control,spawn_mutation_checkboxes}
+    toast::ToastContainer,
+    camera::PANEL_FRACTION,
+    mutation_setting::MutationSettings,
+    systems::StepTimer,
+    theme::Theme,
+    export::ExportSettings,
+    generator::Generator,
+    widgets::{section::spawn_section, FONT},
+    visual_elements::controls::{spawn_language_selector,spawn_new_grid_button,spawn_generator_controls,spawn_pause_button,spawn_speed_control,spawn_view_buttons,spawn_color_mode_buttons,spawn_heatmap_buttons,spawn_selection_buttons,spawn_draw_controls,spawn_library_button,spawn_save_session_button,spawn_mutation_checkboxes,spawn_record_button,spawn_export_buttons,spawn_stabilization_controls,spawn_census_panel,spawn_reset_settings_button},
+    visual_elements::graph::spawn_population_graph,
+    visual_elements::library::spawn_library_panel,
+    visual_elements::{console::spawn_console, help::spawn_help_overlay}
 };
 
-pub fn spawn_left_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+pub fn spawn_left_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme, timer: &StepTimer, settings: &MutationSettings, export: &ExportSettings, generator: &Generator) {
     parent
         .spawn(NodeBundle {
             style: Style {
-                width: Val::Percent(25.0),
+                width: Val::Percent(PANEL_FRACTION * 100.0),
                 height: Val::Percent(100.0),
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::FlexStart,
@@ -15,24 +26,81 @@ pub fn spawn_left_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServe
                 padding: UiRect::all(Val::Px(10.0)),
                 ..default()
             },
-            background_color: Color::srgb(0.1, 0.1, 0.1).into(),
+            background_color: theme.ui.panel.into(),
             ..default()
         })
         .with_children(|ui| {
-            spawn_new_grid_button(ui, asset_server);
-            spawn_speed_control(ui, asset_server);
-            spawn_mutation_checkboxes(ui,asset_server)
+            let font = asset_server.load(FONT);
+            spawn_language_selector(ui, &font, theme);
+            spawn_new_grid_button(ui, &font, theme);
+            spawn_pause_button(ui, &font, theme);
+            spawn_speed_control(ui, &font, theme, timer.speed);
+            spawn_section(ui, &font, theme, "section-generator", false, |ui| {
+                spawn_generator_controls(ui, &font, theme, generator);
+            });
+            spawn_section(ui, &font, theme, "section-view", true, |ui| {
+                spawn_view_buttons(ui, &font, theme);
+                spawn_color_mode_buttons(ui, &font, theme);
+                spawn_heatmap_buttons(ui, &font, theme);
+            });
+            spawn_section(ui, &font, theme, "section-edit", false, |ui| {
+                spawn_selection_buttons(ui, &font, theme);
+                spawn_draw_controls(ui, &font, theme);
+                spawn_library_button(ui, &font, theme);
+            });
+            spawn_section(ui, &font, theme, "section-mutations", true, |ui| {
+                spawn_mutation_checkboxes(ui, &font, theme, settings);
+                spawn_stabilization_controls(ui, &font, theme);
+            });
+            spawn_section(ui, &font, theme, "section-stats", true, |ui| {
+                spawn_population_graph(ui, asset_server, theme);
+                spawn_census_panel(ui, &font, theme);
+            });
+            spawn_section(ui, &font, theme, "section-export", false, |ui| {
+                spawn_record_button(ui, &font, theme);
+                spawn_export_buttons(ui, &font, theme, export);
+                spawn_save_session_button(ui, &font, theme);
+            });
+            spawn_section(ui, &font, theme, "section-settings", false, |ui| {
+                spawn_reset_settings_button(ui, &font, theme);
+            });
         });
 }
 
-pub fn spawn_right_panel(parent: &mut ChildBuilder) {
-    parent.spawn(NodeBundle {
-        style: Style {
-            width: Val::Percent(75.0),
-            height: Val::Percent(100.0),
+pub fn spawn_right_panel(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>, theme: &Theme) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent((1.0 - PANEL_FRACTION) * 100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            ..default()
+        })
+        .with_children(|right| {
+            spawn_library_panel(right, asset_server, theme);
+            spawn_help_overlay(right, asset_server, theme);
+            spawn_console(right, asset_server, theme);
+            spawn_toast_container(right);
+        });
+}
+
+// Notifications empilées en bas à droite de la grille
+fn spawn_toast_container(parent: &mut ChildBuilder) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                right: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
             ..default()
         },
-        background_color: Color::NONE.into(),
-        ..default()
-    });
+        ToastContainer,
+    ));
 }
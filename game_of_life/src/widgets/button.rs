@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use crate::{locale::Localized, theme::Theme};
+
+/// Dimensions d’un bouton, marge autour et taille de son libellé
+#[derive(Clone, Copy, Debug)]
+pub struct ButtonSize {
+    pub width: f32,
+    pub height: f32,
+    pub margin: f32,
+    pub font_size: f32,
+}
+
+impl ButtonSize {
+    /// Bouton principal (« Nouvelle Grille »)
+    pub const LARGE: ButtonSize = ButtonSize { width: 160.0, height: 50.0, margin: 8.0, font_size: 22.0 };
+    pub const WIDE: ButtonSize = ButtonSize { width: 160.0, height: 40.0, margin: 5.0, font_size: 18.0 };
+    pub const MEDIUM: ButtonSize = ButtonSize { width: 160.0, height: 32.0, margin: 5.0, font_size: 18.0 };
+
+    /// Petit bouton des rangées (l’écart vient de la rangée)
+    pub const fn small(width: f32) -> ButtonSize {
+        ButtonSize { width, height: 28.0, margin: 0.0, font_size: 15.0 }
+    }
+}
+
+/// Couleurs du bouton au repos
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ButtonLook {
+    #[default]
+    Normal,
+    /// Action principale
+    Accent,
+    /// Sans fond hors survol (titres de section)
+    Flat,
+}
+
+/// État signalé par la couleur du bouton
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub enum Highlight {
+    #[default]
+    Off,
+    /// Option choisie
+    Active,
+    /// Enregistrement en cours
+    Alert,
+}
+
+pub fn spawn_button(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, size: ButtonSize, look: ButtonLook, message: &'static str, kind: impl Bundle) -> Entity {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(size.width),
+                    height: Val::Px(size.height),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::all(Val::Px(size.margin)),
+                    ..default()
+                },
+                background_color: button_color(theme, look, Highlight::Off, Interaction::None).into(),
+                ..default()
+            },
+            look,
+            Highlight::Off,
+            kind,
+        ))
+        .with_children(|b| {
+            b.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: size.font_size,
+                        color: theme.ui.text,
+                    },
+                ),
+                Localized(message),
+            ));
+        })
+        .id()
+}
+
+// Rangée de petits boutons côte à côte, chacun porteur de son composant et de l’identifiant de son libellé
+pub fn spawn_button_row<T: Bundle>(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, width: f32, buttons: impl IntoIterator<Item = (&'static str, T)>) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(6.0),
+                margin: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            for (message, kind) in buttons {
+                spawn_button(row, font, theme, ButtonSize::small(width), ButtonLook::Normal, message, kind);
+            }
+        });
+}
+
+/// Remplace le message du libellé d’un bouton (texte retraduit par `localize_texts`)
+pub fn set_button_label(children: &Children, labels: &mut Query<&mut Localized>, message: &'static str) {
+    for &child in children {
+        if let Ok(mut label) = labels.get_mut(child)
+            && label.0 != message
+        {
+            label.0 = message;
+        }
+    }
+}
+
+fn button_color(theme: &Theme, look: ButtonLook, highlight: Highlight, interaction: Interaction) -> Color {
+    match look {
+        ButtonLook::Accent => match interaction {
+            Interaction::Pressed => theme.ui.accent_pressed,
+            Interaction::Hovered => theme.ui.accent_hovered,
+            Interaction::None => theme.ui.accent,
+        },
+        _ => {
+            let base = match (look, highlight) {
+                (_, Highlight::Active) => theme.ui.button_active,
+                (_, Highlight::Alert) => theme.ui.button_alert,
+                (ButtonLook::Flat, Highlight::Off) => Color::NONE,
+                _ => theme.ui.button,
+            };
+            // le survol éclaircit (ou assombrit, en thème clair) vers la couleur du texte
+            match (look, interaction) {
+                (ButtonLook::Flat, Interaction::Hovered | Interaction::Pressed) if highlight == Highlight::Off => theme.ui.entry,
+                (_, Interaction::Pressed) => base.mix(&theme.ui.text, 0.25),
+                (_, Interaction::Hovered) => base.mix(&theme.ui.text, 0.12),
+                (_, Interaction::None) => base,
+            }
+        }
+    }
+}
+
+// 🖌️ Couleur des boutons selon leur apparence, leur état et le survol
+pub fn update_button_colors(theme: Res<Theme>, mut buttons: Query<(Ref<Interaction>, Ref<Highlight>, &ButtonLook, &mut BackgroundColor)>) {
+    for (interaction, highlight, look, mut color) in &mut buttons {
+        if interaction.is_changed() || highlight.is_changed() || theme.is_changed() {
+            *color = button_color(&theme, *look, *highlight, *interaction).into();
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use crate::{
+    locale::Localized,
+    theme::Theme,
+    widgets::button::{set_button_label, spawn_button, ButtonLook, ButtonSize, Highlight}
+};
+
+/// Liste déroulante : un bouton montre le choix courant, un clic ouvre la liste des options
+#[derive(Component, Clone, Debug)]
+pub struct Dropdown {
+    /// Messages des options
+    pub options: Vec<&'static str>,
+    pub selected: usize,
+    pub open: bool,
+}
+
+impl Dropdown {
+    pub fn new(options: impl IntoIterator<Item = &'static str>, selected: usize) -> Self {
+        Dropdown { options: options.into_iter().collect(), selected, open: false }
+    }
+}
+
+/// Option choisie dans la liste
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DropdownChanged {
+    pub dropdown: Entity,
+    pub selected: usize,
+}
+
+/// Liste des options de la liste déroulante `0`
+#[derive(Component)]
+pub struct DropdownList(Entity);
+
+#[derive(Component)]
+pub struct DropdownOption {
+    dropdown: Entity,
+    index: usize,
+}
+
+// Bouton + options empilées juste en dessous, par-dessus le reste du panneau ; `kind` va sur le bouton
+pub fn spawn_dropdown(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, size: ButtonSize, dropdown: Dropdown, kind: impl Bundle) -> Entity {
+    let mut header = Entity::PLACEHOLDER;
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|wrapper| {
+            let options = dropdown.options.clone();
+            let message = options.get(dropdown.selected).copied().unwrap_or_default();
+            header = spawn_button(wrapper, font, theme, size, ButtonLook::Normal, message, (dropdown, kind));
+
+            wrapper
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(size.height + size.margin),
+                            left: Val::Px(size.margin),
+                            flex_direction: FlexDirection::Column,
+                            display: Display::None,
+                            ..default()
+                        },
+                        background_color: theme.ui.overlay.into(),
+                        z_index: ZIndex::Global(10),
+                        ..default()
+                    },
+                    DropdownList(header),
+                ))
+                .with_children(|list| {
+                    let option_size = ButtonSize { margin: 0.0, ..size };
+                    for (index, message) in options.into_iter().enumerate() {
+                        spawn_button(list, font, theme, option_size, ButtonLook::Normal, message, DropdownOption { dropdown: header, index });
+                    }
+                });
+        });
+    header
+}
+
+// 🔽 Ouvre / ferme la liste ; choisir une option (ou cliquer ailleurs) la referme
+#[allow(clippy::type_complexity)]
+pub fn dropdown_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    headers: Query<(Entity, &Interaction), (Changed<Interaction>, With<Dropdown>)>,
+    options: Query<(&Interaction, &DropdownOption), Changed<Interaction>>,
+    mut dropdowns: Query<(Entity, &mut Dropdown)>,
+    mut ev_changed: EventWriter<DropdownChanged>,
+) {
+    let mut handled = None;
+    for (entity, interaction) in &headers {
+        if *interaction == Interaction::Pressed
+            && let Ok((_, mut dropdown)) = dropdowns.get_mut(entity)
+        {
+            dropdown.open = !dropdown.open;
+            handled = Some(entity);
+        }
+    }
+    for (interaction, option) in &options {
+        if *interaction == Interaction::Pressed
+            && let Ok((_, mut dropdown)) = dropdowns.get_mut(option.dropdown)
+        {
+            dropdown.open = false;
+            handled = Some(option.dropdown);
+            if dropdown.selected != option.index {
+                dropdown.selected = option.index;
+                ev_changed.send(DropdownChanged { dropdown: option.dropdown, selected: option.index });
+            }
+        }
+    }
+
+    if buttons.just_pressed(MouseButton::Left) {
+        for (entity, mut dropdown) in &mut dropdowns {
+            if dropdown.open && handled != Some(entity) {
+                dropdown.open = false;
+            }
+        }
+    }
+}
+
+// Libellé du choix courant, liste visible ou non, option courante surlignée
+pub fn update_dropdowns(
+    dropdowns: Query<(Entity, Ref<Dropdown>, &Children)>,
+    mut lists: Query<(&DropdownList, &mut Style)>,
+    mut options: Query<(&DropdownOption, &mut Highlight)>,
+    mut labels: Query<&mut Localized>,
+) {
+    for (entity, dropdown, children) in &dropdowns {
+        if !dropdown.is_changed() {
+            continue;
+        }
+        if let Some(message) = dropdown.options.get(dropdown.selected) {
+            set_button_label(children, &mut labels, message);
+        }
+        for (list, mut style) in &mut lists {
+            if list.0 == entity {
+                style.display = if dropdown.open { Display::Flex } else { Display::None };
+            }
+        }
+        for (option, mut highlight) in &mut options {
+            if option.dropdown == entity {
+                let selected = if option.index == dropdown.selected { Highlight::Active } else { Highlight::Off };
+                if *highlight != selected {
+                    *highlight = selected;
+                }
+            }
+        }
+    }
+}
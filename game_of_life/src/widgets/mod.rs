@@ -0,0 +1,48 @@
+//! Éléments d’interface réutilisables : chaque widget garde son état dans un composant,
+//! signale les changements par un événement, et le code appelant le reconnaît à son composant `kind`
+pub mod button;
+pub mod toggle;
+pub mod slider;
+pub mod number;
+pub mod dropdown;
+pub mod text_field;
+pub mod section;
+
+use bevy::prelude::*;
+
+/// Police de tous les widgets
+pub const FONT: &str = "fonts/FiraSans-Bold.ttf";
+
+/// Widget qui prend le clavier quand on clique dessus (curseur, nombre, champ de texte)
+#[derive(Component)]
+pub struct Focusable;
+
+/// Widget qui a le clavier ; les raccourcis de la grille sont suspendus tant qu’il y en a un
+#[derive(Resource, Default)]
+pub struct WidgetFocus {
+    pub focused: Option<Entity>,
+}
+
+impl WidgetFocus {
+    pub fn captures_keyboard(&self) -> bool {
+        self.focused.is_some()
+    }
+}
+
+// 🎯 Un clic donne le focus au widget visé (ou le retire) ; Échap le rend à la grille
+pub fn focus_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focusables: Query<(Entity, &Interaction), With<Focusable>>,
+    mut focus: ResMut<WidgetFocus>,
+) {
+    if buttons.just_pressed(MouseButton::Left) {
+        let pressed = focusables.iter().find(|(_, i)| **i == Interaction::Pressed).map(|(e, _)| e);
+        if focus.focused != pressed {
+            focus.focused = pressed;
+        }
+    }
+    if keys.just_pressed(KeyCode::Escape) && focus.focused.is_some() {
+        focus.focused = None;
+    }
+}
@@ -0,0 +1,234 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use crate::{
+    locale::Localized,
+    theme::Theme,
+    widgets::{button::{ButtonLook, Highlight}, text_field::{edit, Edit}, Focusable, WidgetFocus}
+};
+
+/// Nombre réglé par − / +, par les flèches ou en tapant sa valeur (Entrée pour valider)
+#[derive(Component, Clone, Debug)]
+pub struct NumberInput {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Écart ajouté par + et retiré par −
+    pub step: f32,
+    pub decimals: usize,
+    /// Saisie au clavier pas encore validée
+    editing: Option<String>,
+}
+
+impl NumberInput {
+    pub fn new(min: f32, max: f32, step: f32, value: f32) -> Self {
+        let mut input = NumberInput { value, min, max, step, decimals: 0, editing: None };
+        input.set_value(value);
+        input
+    }
+
+    /// Valeur bornée et arrondie au nombre de décimales affichées
+    pub fn set_value(&mut self, value: f32) {
+        let scale = 10f32.powi(self.decimals as i32);
+        self.value = ((value * scale).round() / scale).clamp(self.min, self.max);
+    }
+
+    fn text(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+
+    /// Valide la saisie en cours ; vrai si la valeur a changé
+    fn commit(&mut self) -> bool {
+        let before = self.value;
+        if let Some(value) = self.editing.take().and_then(|text| text.replace(',', ".").parse::<f32>().ok()) {
+            self.set_value(value);
+        }
+        self.value != before
+    }
+}
+
+/// Nouvelle valeur validée
+#[derive(Event, Clone, Copy, Debug)]
+pub struct NumberChanged {
+    pub input: Entity,
+    pub value: f32,
+}
+
+/// Bouton − (`-1.0`) ou + (`1.0`) du nombre parent
+#[derive(Component)]
+pub struct NumberStep(f32);
+
+/// Case où s’affiche la valeur
+#[derive(Component)]
+pub struct NumberValue;
+
+// Libellé, −, valeur, + ; la rangée porte le nombre et `kind`, un clic dessus lui donne le clavier
+pub fn spawn_number_input(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, message: &'static str, input: NumberInput, kind: impl Bundle) -> Entity {
+    let style = |size: f32| TextStyle { font: font.clone(), font_size: size, color: theme.ui.text };
+    let step_button = |row: &mut ChildBuilder, label: &str, steps: f32| {
+        row.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(26.0),
+                    height: Val::Px(26.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: theme.ui.button.into(),
+                ..default()
+            },
+            ButtonLook::Normal,
+            Highlight::Off,
+            NumberStep(steps),
+        ))
+        .with_children(|b| {
+            b.spawn(TextBundle::from_section(label, style(18.0)));
+        });
+    };
+
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    margin: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            Interaction::default(),
+            Focusable,
+            input,
+            kind,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                TextBundle::from_section("", style(16.0)).with_style(Style {
+                    margin: UiRect::right(Val::Px(4.0)),
+                    ..default()
+                }),
+                Localized(message),
+            ));
+            step_button(row, "−", -1.0);
+            row.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(56.0),
+                        height: Val::Px(26.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: theme.ui.graph_background.into(),
+                    border_color: BorderColor(theme.ui.text_dim),
+                    ..default()
+                },
+                NumberValue,
+            ))
+            .with_children(|b| {
+                // écrit par `update_number_inputs`
+                b.spawn(TextBundle::from_section("", style(16.0)));
+            });
+            step_button(row, "+", 1.0);
+        })
+        .id()
+}
+
+pub fn number_step_system(
+    steps: Query<(&Interaction, &NumberStep, &Parent), Changed<Interaction>>,
+    mut inputs: Query<&mut NumberInput>,
+    mut ev_changed: EventWriter<NumberChanged>,
+) {
+    for (interaction, step, parent) in &steps {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut input) = inputs.get_mut(parent.get())
+        {
+            let before = input.value;
+            let value = input.value + step.0 * input.step;
+            input.editing = None;
+            input.set_value(value);
+            if input.value != before {
+                ev_changed.send(NumberChanged { input: parent.get(), value: input.value });
+            }
+        }
+    }
+}
+
+// ⌨️ Nombre actif : flèches (un pas), Page préc./suiv. (dix pas), chiffres puis Entrée ; Échap annule la saisie
+pub fn number_keyboard_system(
+    mut keyboard: EventReader<KeyboardInput>,
+    focus: Res<WidgetFocus>,
+    mut inputs: Query<(Entity, &mut NumberInput)>,
+    mut ev_changed: EventWriter<NumberChanged>,
+) {
+    let events: Vec<&KeyboardInput> = keyboard.read().filter(|e| e.state == ButtonState::Pressed).collect();
+    for (entity, mut input) in &mut inputs {
+        // une saisie laissée en plan (clic ailleurs) est validée
+        if focus.focused != Some(entity) {
+            if input.editing.is_some() && input.commit() {
+                ev_changed.send(NumberChanged { input: entity, value: input.value });
+            }
+            continue;
+        }
+
+        let before = input.value;
+        for event in &events {
+            let steps = match &event.logical_key {
+                Key::ArrowUp | Key::ArrowRight => 1.0,
+                Key::ArrowDown | Key::ArrowLeft => -1.0,
+                Key::PageUp => 10.0,
+                Key::PageDown => -10.0,
+                key => {
+                    let mut text = input.editing.clone().unwrap_or_default();
+                    match edit(&mut text, key, 12, |c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-')) {
+                        Edit::Changed => input.editing = Some(text),
+                        Edit::Submit => {
+                            input.commit();
+                        }
+                        Edit::Cancel => input.editing = None,
+                        Edit::Ignored => {}
+                    }
+                    continue;
+                }
+            };
+            input.editing = None;
+            let value = input.value + steps * input.step;
+            input.set_value(value);
+        }
+        if input.value != before {
+            ev_changed.send(NumberChanged { input: entity, value: input.value });
+        }
+    }
+}
+
+// Valeur (ou saisie en cours suivie de « | »), bordure du nombre actif
+pub fn update_number_inputs(
+    theme: Res<Theme>,
+    focus: Res<WidgetFocus>,
+    inputs: Query<(Entity, Ref<NumberInput>, &Children)>,
+    mut boxes: Query<(&Children, &mut BorderColor), With<NumberValue>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, input, children) in &inputs {
+        if !input.is_changed() && !focus.is_changed() && !theme.is_changed() {
+            continue;
+        }
+        let focused = focus.focused == Some(entity);
+        let mut boxes = boxes.iter_many_mut(children);
+        while let Some((box_children, mut border)) = boxes.fetch_next() {
+            border.0 = if focused { theme.ui.button_active } else { theme.ui.text_dim };
+            for &child in box_children {
+                if let Ok(mut text) = texts.get_mut(child) {
+                    text.sections[0].value = match &input.editing {
+                        Some(editing) => format!("{editing}|"),
+                        None => input.text(),
+                    };
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use crate::{
+    locale::Localized,
+    theme::Theme,
+    widgets::button::{ButtonLook, Highlight}
+};
+
+/// Titre d’un groupe de widgets repliable
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Section {
+    pub open: bool,
+}
+
+/// Signe « + » / « − » et contenu de la section
+#[derive(Component)]
+pub struct SectionParts {
+    sign: Entity,
+    content: Entity,
+}
+
+// Titre cliquable puis le contenu déclaré par `content`, masqué quand la section est repliée
+pub fn spawn_section(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, title: &'static str, open: bool, content: impl FnOnce(&mut ChildBuilder)) -> Entity {
+    let style = TextStyle { font: font.clone(), font_size: 18.0, color: theme.ui.text_dim };
+    let mut header = parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Px(26.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::horizontal(Val::Px(6.0)),
+                margin: UiRect::top(Val::Px(6.0)),
+                border: UiRect::bottom(Val::Px(1.0)),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            border_color: BorderColor(theme.ui.graph_border),
+            ..default()
+        },
+        ButtonLook::Flat,
+        Highlight::Off,
+        Section { open },
+    ));
+    let mut sign = Entity::PLACEHOLDER;
+    header.with_children(|h| {
+        sign = h.spawn(TextBundle::from_section(if open { "−" } else { "+" }, style.clone())).id();
+        h.spawn((TextBundle::from_section("", style), Localized(title)));
+    });
+    let header = header.id();
+
+    let content = parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                display: if open { Display::Flex } else { Display::None },
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(content)
+        .id();
+
+    parent.add_command(move |world: &mut World| {
+        world.entity_mut(header).insert(SectionParts { sign, content });
+    });
+    header
+}
+
+pub fn section_system(mut sections: Query<(&Interaction, &mut Section), Changed<Interaction>>) {
+    for (interaction, mut section) in &mut sections {
+        if *interaction == Interaction::Pressed {
+            section.open = !section.open;
+        }
+    }
+}
+
+// 📂 Déplie ou replie le contenu
+pub fn update_sections(
+    sections: Query<(Ref<Section>, &SectionParts)>,
+    mut styles: Query<&mut Style>,
+    mut texts: Query<&mut Text>,
+) {
+    for (section, parts) in &sections {
+        if !section.is_changed() {
+            continue;
+        }
+        if let Ok(mut style) = styles.get_mut(parts.content) {
+            style.display = if section.open { Display::Flex } else { Display::None };
+        }
+        if let Ok(mut text) = texts.get_mut(parts.sign) {
+            text.sections[0].value = if section.open { "−" } else { "+" }.to_string();
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::{locale::Localization, theme::Theme, widgets::{Focusable, WidgetFocus}};
+
+/// Largeur de la barre, en pixels
+pub const SLIDER_WIDTH: f32 = 100.0;
+const HANDLE_WIDTH: f32 = 4.0;
+/// Déplacement au clavier d’un curseur continu (`step` nul), en fraction de la plage
+const KEYBOARD_FRACTION: f32 = 0.05;
+
+/// Curseur numérique : `min` à gauche, `max` à droite (`min` peut dépasser `max`)
+#[derive(Component, Clone, Debug)]
+pub struct Slider {
+    pub min: f32,
+    pub max: f32,
+    /// Pas entre deux valeurs possibles (0 : continu)
+    pub step: f32,
+    pub value: f32,
+    /// Message du libellé affiché à droite ; `{ $value }` y reçoit la valeur
+    pub label: &'static str,
+    /// Chiffres après la virgule dans le libellé
+    pub decimals: usize,
+}
+
+impl Slider {
+    pub fn new(min: f32, max: f32, value: f32) -> Self {
+        let mut slider = Slider { min, max, step: 0.0, value, label: "", decimals: 2 };
+        slider.set_value(value);
+        slider
+    }
+
+    /// Position de la valeur sur la barre, de 0 (gauche) à 1 (droite)
+    pub fn ratio(&self) -> f32 {
+        if self.max == self.min {
+            return 0.0;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Valeur bornée à la plage et arrondie au pas
+    pub fn set_value(&mut self, value: f32) {
+        let mut value = value.clamp(self.min.min(self.max), self.min.max(self.max));
+        if self.step > 0.0 {
+            value = self.min + ((value - self.min) / self.step).round() * self.step;
+            value = value.clamp(self.min.min(self.max), self.min.max(self.max));
+        }
+        self.value = value;
+    }
+
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.set_value(self.min + ratio.clamp(0.0, 1.0) * (self.max - self.min));
+    }
+
+    /// Décale la valeur de `steps` crans vers la droite (négatif : vers la gauche)
+    pub fn nudge(&mut self, steps: f32) {
+        let step = if self.step > 0.0 { self.step } else { (self.max - self.min).abs() * KEYBOARD_FRACTION };
+        self.set_value(self.value + steps * step * (self.max - self.min).signum());
+    }
+
+    pub fn text(&self, loc: &Localization) -> String {
+        loc.t_with(self.label, &[("value", format!("{:.*}", self.decimals, self.value).into())])
+    }
+}
+
+/// Nouvelle valeur choisie à la souris ou au clavier
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderChanged {
+    pub slider: Entity,
+    pub value: f32,
+}
+
+#[derive(Component)]
+pub struct SliderHandle;
+
+/// Libellé du curseur `0`
+#[derive(Component)]
+pub struct SliderText(Entity);
+
+///////////////
+//   Spawn   //
+///////////////
+
+// Barre + libellé côte à côte ; `kind` permet au code appelant de reconnaître son curseur
+pub fn spawn_slider(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, slider: Slider, kind: impl Component) -> Entity {
+    let mut bar = Entity::PLACEHOLDER;
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(SLIDER_WIDTH * 2.0),
+                height: Val::Px(30.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                margin: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            bar = row
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(SLIDER_WIDTH),
+                            height: Val::Px(20.0),
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        background_color: theme.ui.button.into(),
+                        border_color: BorderColor(theme.ui.text),
+                        ..default()
+                    },
+                    Interaction::default(),
+                    Focusable,
+                    slider,
+                    kind,
+                ))
+                .with_children(|bar| {
+                    // placé par `update_sliders`
+                    bar.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(HANDLE_WIDTH),
+                                height: Val::Px(24.0),
+                                margin: UiRect::left(Val::Px(-HANDLE_WIDTH / 2.0)),
+                                position_type: PositionType::Absolute,
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        SliderHandle,
+                    ));
+                })
+                .id();
+            row.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 18.0,
+                        color: theme.ui.text,
+                    },
+                ),
+                SliderText(bar),
+            ));
+        });
+    bar
+}
+
+///////////////
+//  Systems  //
+///////////////
+
+// 🖱️ Un clic sur la barre capture la souris jusqu’au relâchement, même hors de la barre
+pub fn slider_drag_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut sliders: Query<(Entity, &mut Slider, &Interaction, &Node, &GlobalTransform)>,
+    mut dragging: Local<Option<Entity>>,
+    mut ev_changed: EventWriter<SliderChanged>,
+) {
+    if buttons.just_pressed(MouseButton::Left) {
+        *dragging = sliders.iter().find(|(_, _, interaction, ..)| **interaction == Interaction::Pressed).map(|(e, ..)| e);
+    }
+    if !buttons.pressed(MouseButton::Left) {
+        *dragging = None;
+    }
+
+    let Some(entity) = *dragging else {
+        return;
+    };
+    let (Ok(window), Ok((_, mut slider, _, node, transform))) = (windows.get_single(), sliders.get_mut(entity)) else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let left = transform.translation().x - node.size().x / 2.0;
+    let ratio = (cursor.x - left) / node.size().x.max(1.0);
+
+    let before = slider.value;
+    slider.bypass_change_detection().set_ratio(ratio);
+    if slider.value != before {
+        slider.set_changed();
+        ev_changed.send(SliderChanged { slider: entity, value: slider.value });
+    }
+}
+
+// ⌨️ Curseur actif : flèches (un cran), Page préc./suiv. (dix crans), Début/Fin
+pub fn slider_keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<WidgetFocus>,
+    mut sliders: Query<&mut Slider>,
+    mut ev_changed: EventWriter<SliderChanged>,
+) {
+    let Some(entity) = focus.focused else {
+        return;
+    };
+    let Ok(mut slider) = sliders.get_mut(entity) else {
+        return;
+    };
+
+    let before = slider.value;
+    let target = slider.bypass_change_detection();
+    for key in keys.get_just_pressed() {
+        match key {
+            KeyCode::ArrowLeft | KeyCode::ArrowDown => target.nudge(-1.0),
+            KeyCode::ArrowRight | KeyCode::ArrowUp => target.nudge(1.0),
+            KeyCode::PageDown => target.nudge(-10.0),
+            KeyCode::PageUp => target.nudge(10.0),
+            KeyCode::Home => target.set_ratio(0.0),
+            KeyCode::End => target.set_ratio(1.0),
+            _ => {}
+        }
+    }
+    if slider.value != before {
+        slider.set_changed();
+        ev_changed.send(SliderChanged { slider: entity, value: slider.value });
+    }
+}
+
+// Poignée, libellé et bordure (curseur actif) suivent la valeur, la langue et le focus
+pub fn update_sliders(
+    loc: Res<Localization>,
+    theme: Res<Theme>,
+    focus: Res<WidgetFocus>,
+    mut sliders: Query<(Entity, Ref<Slider>, &Children, &mut BorderColor)>,
+    mut handles: Query<(&mut Style, &mut BackgroundColor), With<SliderHandle>>,
+    mut texts: Query<(&SliderText, &mut Text)>,
+) {
+    for (entity, slider, children, mut border) in &mut sliders {
+        if focus.is_changed() || theme.is_changed() {
+            border.0 = if focus.focused == Some(entity) { theme.ui.button_active } else { theme.ui.text };
+        }
+        if !slider.is_changed() {
+            continue;
+        }
+        let ratio = slider.ratio();
+        for &child in children {
+            if let Ok((mut style, mut color)) = handles.get_mut(child) {
+                style.left = Val::Percent(ratio * 100.0);
+                *color = Color::srgb(1.0 - ratio, 1.0, 0.2).into();
+            }
+        }
+    }
+
+    for (link, mut text) in &mut texts {
+        if let Ok((_, slider, ..)) = sliders.get(link.0)
+            && (slider.is_changed() || loc.is_changed())
+        {
+            text.sections[0].value = slider.text(&loc);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use crate::{locale::Localization, theme::Theme, widgets::{Focusable, WidgetFocus}};
+
+/// Champ de texte d’une ligne ; clic pour saisir, Entrée ou Échap pour rendre le clavier
+#[derive(Component, Clone, Debug, Default)]
+pub struct TextField {
+    pub text: String,
+    /// Message affiché en gris quand le champ est vide
+    pub placeholder: &'static str,
+    /// Nombre maximal de caractères (0 : sans limite)
+    pub max_len: usize,
+}
+
+/// Texte modifié au clavier
+#[derive(Event, Clone, Debug)]
+pub struct TextChanged {
+    pub field: Entity,
+    pub text: String,
+}
+
+/// Effet d’une touche sur un texte en cours de saisie
+pub(crate) enum Edit {
+    Changed,
+    Submit,
+    Cancel,
+    Ignored,
+}
+
+/// Applique une touche au texte : caractères, espace, effacement arrière, Entrée, Échap
+pub(crate) fn edit(text: &mut String, key: &Key, max_len: usize, accept: impl Fn(char) -> bool) -> Edit {
+    let full = max_len > 0 && text.chars().count() >= max_len;
+    match key {
+        Key::Character(chars) => {
+            let chars: Vec<char> = chars.chars().filter(|c| !c.is_control() && accept(*c)).collect();
+            if chars.is_empty() || full {
+                return Edit::Ignored;
+            }
+            text.extend(chars);
+            Edit::Changed
+        }
+        Key::Space if !full && accept(' ') => {
+            text.push(' ');
+            Edit::Changed
+        }
+        Key::Backspace if text.pop().is_some() => Edit::Changed,
+        Key::Enter => Edit::Submit,
+        Key::Escape => Edit::Cancel,
+        _ => Edit::Ignored,
+    }
+}
+
+pub fn spawn_text_field(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, width: f32, field: TextField, kind: impl Bundle) -> Entity {
+    parent
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(26.0),
+                    padding: UiRect::horizontal(Val::Px(6.0)),
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                background_color: theme.ui.graph_background.into(),
+                border_color: BorderColor(theme.ui.text_dim),
+                ..default()
+            },
+            Interaction::default(),
+            Focusable,
+            field,
+            kind,
+        ))
+        .with_children(|b| {
+            // écrit par `update_text_fields`
+            b.spawn(TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    color: theme.ui.text,
+                },
+            ));
+        })
+        .id()
+}
+
+// ⌨️ Saisie dans le champ qui a le focus
+pub fn text_field_system(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut focus: ResMut<WidgetFocus>,
+    mut fields: Query<&mut TextField>,
+    mut ev_changed: EventWriter<TextChanged>,
+) {
+    let Some(entity) = focus.focused else {
+        keyboard.clear();
+        return;
+    };
+    let Ok(mut field) = fields.get_mut(entity) else {
+        keyboard.clear();
+        return;
+    };
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        let max_len = field.max_len;
+        match edit(&mut field.text, &input.logical_key, max_len, |_| true) {
+            Edit::Changed => {
+                ev_changed.send(TextChanged { field: entity, text: field.text.clone() });
+            }
+            Edit::Submit | Edit::Cancel => focus.focused = None,
+            Edit::Ignored => {}
+        }
+    }
+}
+
+// Texte (avec un curseur « | » pendant la saisie) ou message d’invite, bordure du champ actif
+pub fn update_text_fields(
+    loc: Res<Localization>,
+    theme: Res<Theme>,
+    focus: Res<WidgetFocus>,
+    mut fields: Query<(Entity, Ref<TextField>, &Children, &mut BorderColor)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, field, children, mut border) in &mut fields {
+        if !field.is_changed() && !focus.is_changed() && !loc.is_changed() && !theme.is_changed() {
+            continue;
+        }
+        let focused = focus.focused == Some(entity);
+        border.0 = if focused { theme.ui.button_active } else { theme.ui.text_dim };
+        for &child in children {
+            if let Ok(mut text) = texts.get_mut(child) {
+                let section = &mut text.sections[0];
+                (section.value, section.style.color) = match (field.text.is_empty(), focused) {
+                    (true, false) => (loc.t(field.placeholder), theme.ui.text_dim),
+                    (_, true) => (format!("{}|", field.text), theme.ui.text),
+                    (false, false) => (field.text.clone(), theme.ui.text),
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use crate::{locale::Localized, theme::Theme};
+
+const BOX_SIZE: f32 = 24.0;
+const MARK_SIZE: f32 = 14.0;
+
+/// Case à cocher : une coche pleine au centre de la case quand elle est cochée
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Toggle {
+    pub checked: bool,
+    /// Couleur de la coche (celle des options actives si absente)
+    pub color: Option<Color>,
+}
+
+/// Case cochée ou décochée d’un clic
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ToggleChanged {
+    pub toggle: Entity,
+    pub checked: bool,
+}
+
+#[derive(Component)]
+pub struct ToggleMark;
+
+// Case + libellé ; `kind` va sur la case
+pub fn spawn_toggle(parent: &mut ChildBuilder, font: &Handle<Font>, theme: &Theme, message: &'static str, toggle: Toggle, kind: impl Bundle) -> Entity {
+    let mut case = Entity::PLACEHOLDER;
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                margin: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            // petit carré cliquable
+            case = row
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(BOX_SIZE),
+                            height: Val::Px(BOX_SIZE),
+                            margin: UiRect::right(Val::Px(10.0)),
+                            border: UiRect::all(Val::Px(2.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        background_color: theme.ui.button.into(),
+                        border_color: BorderColor(theme.ui.text_dim),
+                        ..default()
+                    },
+                    toggle,
+                    kind,
+                ))
+                .with_children(|b| {
+                    // affichée par `update_toggles`
+                    b.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(MARK_SIZE),
+                                height: Val::Px(MARK_SIZE),
+                                ..default()
+                            },
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        ToggleMark,
+                    ));
+                })
+                .id();
+
+            // texte à côté
+            row.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        color: theme.ui.text,
+                    },
+                ),
+                Localized(message),
+            ));
+        });
+    case
+}
+
+pub fn toggle_system(
+    mut toggles: Query<(Entity, &Interaction, &mut Toggle), Changed<Interaction>>,
+    mut ev_changed: EventWriter<ToggleChanged>,
+) {
+    for (entity, interaction, mut toggle) in &mut toggles {
+        if *interaction == Interaction::Pressed {
+            toggle.checked = !toggle.checked;
+            ev_changed.send(ToggleChanged { toggle: entity, checked: toggle.checked });
+        }
+    }
+}
+
+// ☑️ La coche suit l’état de la case ; la bordure s’allume au survol
+pub fn update_toggles(
+    theme: Res<Theme>,
+    mut toggles: Query<(Ref<Toggle>, Ref<Interaction>, &Children, &mut BorderColor)>,
+    mut marks: Query<(&mut Visibility, &mut BackgroundColor), With<ToggleMark>>,
+) {
+    for (toggle, interaction, children, mut border) in &mut toggles {
+        if !toggle.is_changed() && !interaction.is_changed() && !theme.is_changed() {
+            continue;
+        }
+        border.0 = match *interaction {
+            Interaction::None => theme.ui.text_dim,
+            _ => theme.ui.text,
+        };
+        for &child in children {
+            if let Ok((mut visibility, mut color)) = marks.get_mut(child) {
+                *visibility = if toggle.checked { Visibility::Inherited } else { Visibility::Hidden };
+                *color = toggle.color.unwrap_or(theme.ui.button_active).into();
+            }
+        }
+    }
+}